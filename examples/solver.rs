@@ -30,6 +30,7 @@ fn main() {
         naked_twins: matches.get_flag("use-naked-twins"),
         hidden_twins: matches.get_flag("use-hidden-twins"),
         xwings: matches.get_flag("use-xwings"),
+        ..DefaultSolverConfig::default()
     };
 
     println!("Strategies:");
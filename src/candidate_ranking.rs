@@ -0,0 +1,99 @@
+use crate::cell_group::CellGroups;
+use crate::game_state::GameState;
+use crate::index::Index;
+use crate::value::Value;
+
+/// A single ranked candidate assignment produced by [`CandidateRanking`].
+///
+/// `confidence` is the estimated probability, in `[0, 1]`, that the cell at
+/// `index` holds `value`. Assignments are returned sorted by descending
+/// confidence so the solver can branch on the most certain cell first.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RankedAssignment {
+    pub index: Index,
+    pub value: Value,
+    pub confidence: f32,
+}
+
+/// Estimates a probability distribution over the remaining candidates of every
+/// unsolved cell and exposes the most confident assignments.
+///
+/// The estimator is a constraint-counting heuristic: within each group a value
+/// that still has `k` candidate positions contributes a base weight `1/k` to
+/// each of those cells. The weights a cell receives for a value across its
+/// groups are multiplied, then the per-cell scores are renormalized over the
+/// cell's candidate set to form a probability distribution. The pass is purely
+/// read-only and never mutates candidates.
+pub struct CandidateRanking;
+
+impl CandidateRanking {
+    /// Returns the ranked assignments, one per unsolved cell (its most likely
+    /// value), sorted by descending confidence.
+    pub fn rank(state: &GameState, groups: &CellGroups) -> Vec<RankedAssignment> {
+        let mut assignments = Vec::new();
+
+        for index in Index::range() {
+            let cell = state.get_at_index(index);
+            if cell.is_solved() || cell.is_impossible() {
+                continue;
+            }
+
+            let cell_groups = match groups.get_groups_at_index(index) {
+                Ok(groups) => groups,
+                Err(_) => continue,
+            };
+
+            // Accumulate the per-value scores as the product of the per-group
+            // probabilities that this cell holds the value.
+            let mut scores: Vec<(Value, f32)> = Vec::new();
+            for value in cell.iter_candidates() {
+                let mut score = 1.0_f32;
+                for group in &cell_groups {
+                    // Number of unsolved cells in this group that can hold the value.
+                    let k = group
+                        .iter_indexes()
+                        .filter(|&i| {
+                            let c = state.get_at_index(i);
+                            !c.is_solved() && c.contains(value)
+                        })
+                        .count();
+                    if k == 0 {
+                        continue;
+                    }
+                    score *= 1.0 / k as f32;
+                }
+                scores.push((value, score));
+            }
+
+            let total: f32 = scores.iter().map(|&(_, s)| s).sum();
+            if total <= 0.0 {
+                continue;
+            }
+
+            // Pick the most likely value for this cell after renormalization.
+            if let Some(&(value, score)) = scores
+                .iter()
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            {
+                assignments.push(RankedAssignment {
+                    index,
+                    value,
+                    confidence: score / total,
+                });
+            }
+        }
+
+        assignments.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        assignments
+    }
+
+    /// Returns the single most confident `(cell, value)` assignment, if any
+    /// unsolved cell remains.
+    pub fn best(state: &GameState, groups: &CellGroups) -> Option<RankedAssignment> {
+        Self::rank(state, groups).into_iter().next()
+    }
+}
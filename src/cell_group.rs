@@ -61,6 +61,83 @@ impl CellGroups {
         self.with_default_rows().with_default_columns()
     }
 
+    /// Adds rows, columns and blocks for a Sudoku of arbitrary order.
+    ///
+    /// The `box_side` is the side length of a single block (`3` for a classic
+    /// 9×9 board), so the resulting grid has `box_side * box_side` cells per
+    /// row, column and block. The cell storage is currently fixed to 81 cells
+    /// ([`Index`] ranges over `0..81` and [`Coordinate`] is 9-wide), so orders
+    /// beyond `3` (9×9) describe the group layout but cannot yet be solved
+    /// end-to-end: a 4×4 board leaves the unused cells unconstrained and a
+    /// 16×16 board overflows the index range entirely.
+    ///
+    /// For that reason the 4×4 (shidoku) and 16×16 (hexadoku) `example_games`
+    /// fixtures and their benchmarks are deferred until [`Index`]/[`Coordinate`]
+    /// are widened to the board size; only the group-layout builders ship here.
+    ///
+    /// [`Index`]: crate::index::Index
+    /// [`Coordinate`]: crate::coordinate::Coordinate
+    pub fn with_sudoku_order(self, box_side: u8) -> Self {
+        self.with_sudoku_blocks(box_side)
+            .with_rows(box_side)
+            .with_columns(box_side)
+    }
+
+    /// Adds the row groups for a Sudoku of the given order, see
+    /// [`CellGroups::with_sudoku_order`].
+    pub fn with_rows(mut self, box_side: u8) -> Self {
+        let side = box_side * box_side;
+        let mut ids = self.get_highest_id();
+
+        for y in 0..side {
+            let mut group = CellGroup::new(ids, CellGroupType::StandardRow);
+            ids += 1;
+            for x in 0..side {
+                group.add_index(Index::new(y * side + x));
+            }
+            self.add_group(group);
+        }
+        self
+    }
+
+    /// Adds the column groups for a Sudoku of the given order, see
+    /// [`CellGroups::with_sudoku_order`].
+    pub fn with_columns(mut self, box_side: u8) -> Self {
+        let side = box_side * box_side;
+        let mut ids = self.get_highest_id();
+
+        for x in 0..side {
+            ids += 1;
+            let mut group = CellGroup::new(ids, CellGroupType::StandardColumn);
+            for y in 0..side {
+                group.add_index(Index::new(y * side + x));
+            }
+            self.add_group(group);
+        }
+        self
+    }
+
+    /// Adds the block groups for a Sudoku of the given order, see
+    /// [`CellGroups::with_sudoku_order`].
+    pub fn with_sudoku_blocks(mut self, box_side: u8) -> Self {
+        let side = box_side * box_side;
+        let mut ids = self.get_highest_id();
+
+        for y in (0..side).step_by(box_side as usize) {
+            for x in (0..side).step_by(box_side as usize) {
+                ids += 1;
+                let mut group = CellGroup::new(ids, CellGroupType::StandardBlock);
+                for row in 0..box_side {
+                    for col in 0..box_side {
+                        group.add_index(Index::new((y + row) * side + (x + col)));
+                    }
+                }
+                self.add_group(group);
+            }
+        }
+        self
+    }
+
     //noinspection DuplicatedCode
     fn with_default_rows(mut self) -> Self {
         let mut check = IndexBitSet::ALL;
@@ -137,6 +214,18 @@ impl CellGroups {
         self
     }
 
+    /// Adds the two main diagonals as extra groups, turning a board into an
+    /// X-Sudoku (diagonal Sudoku).
+    ///
+    /// The diagonals are registered as [`CellGroupType::Custom`] groups so they
+    /// compose freely with the standard rows, columns and blocks as well as the
+    /// hypersudoku windows.
+    pub fn with_diagonals(self) -> Self {
+        let main: Vec<u8> = (0..9u8).map(|i| i * 9 + i).collect();
+        let anti: Vec<u8> = (0..9u8).map(|i| i * 9 + (8 - i)).collect();
+        self.with_group_from_iter(main).with_group_from_iter(anti)
+    }
+
     pub fn with_hypersudoku_windows(self) -> Self {
         self.with_group_from_iter([10, 11, 12, 19, 20, 21, 28, 29, 30])
             .with_group_from_iter([14, 15, 16, 23, 24, 25, 32, 33, 34])
@@ -231,6 +320,169 @@ impl CellGroups {
     pub fn iter(&self) -> Iter<'_, CellGroup> {
         self.groups.iter()
     }
+
+    /// Builds block groups from a paint-by-numbers region-id array (one id per
+    /// cell) alongside the standard rows and columns, validating that the
+    /// regions form a proper partition of the board.
+    ///
+    /// ## Errors
+    /// Returns a [`PartitionError`] if the regions overlap, leave cells
+    /// uncovered or are not orthogonally connected, see
+    /// [`CellGroups::validate_partition`].
+    pub fn from_region_map(regions: &[u8; 81]) -> Result<Self, PartitionError> {
+        let mut groups = CellGroups::default();
+        let mut by_region: Vec<(u8, CellGroup)> = Vec::new();
+
+        for (i, &region) in regions.iter().enumerate() {
+            let group = match by_region.iter_mut().find(|(id, _)| *id == region) {
+                Some((_, group)) => group,
+                None => {
+                    by_region.push((
+                        region,
+                        CellGroup::new(region as usize, CellGroupType::StandardBlock),
+                    ));
+                    &mut by_region.last_mut().unwrap().1
+                }
+            };
+            group.add_index(Index::new(i as u8));
+        }
+
+        for (_, group) in by_region {
+            groups.add_group(group);
+        }
+
+        let groups = groups.with_default_rows_and_columns();
+        groups.validate_partition()?;
+        Ok(groups)
+    }
+
+    /// Validates that the [`StandardBlock`](CellGroupType::StandardBlock) groups
+    /// form a proper partition of the 81-cell board: every cell belongs to
+    /// exactly one region and every region is a single orthogonally-connected
+    /// blob.
+    ///
+    /// The connectivity check runs a disjoint-set union over the cells, uniting
+    /// orthogonally-adjacent cells of the same region; a region is connected iff
+    /// all of its cells end up in a single component of the region's size.
+    pub fn validate_partition(&self) -> Result<(), PartitionError> {
+        // Map each cell to the region that owns it, reporting overlaps and
+        // uncovered cells along the way.
+        let mut region_of: [Option<usize>; 81] = [None; 81];
+        for group in self
+            .groups
+            .iter()
+            .filter(|g| g.group_type == CellGroupType::StandardBlock)
+        {
+            let region = group.id.unwrap_or_default();
+            for index in group.iter_indexes() {
+                let slot = &mut region_of[*index as usize];
+                if slot.is_some() {
+                    return Err(PartitionError::OverlappingCell { index: *index });
+                }
+                *slot = Some(region);
+            }
+        }
+
+        for (i, region) in region_of.iter().enumerate() {
+            if region.is_none() {
+                return Err(PartitionError::UncoveredCell { index: i as u8 });
+            }
+        }
+
+        // Union-find with union by size; `parent[i]` holds the negated component
+        // size for a root and the parent index otherwise.
+        let mut parent: [i32; 81] = [-1; 81];
+        for i in 0..81u8 {
+            let (x, y) = (i % 9, i / 9);
+            // Only the right and down neighbours are needed to cover all edges.
+            if x + 1 < 9 {
+                Self::unite(&mut parent, &region_of, i, i + 1);
+            }
+            if y + 1 < 9 {
+                Self::unite(&mut parent, &region_of, i, i + 9);
+            }
+        }
+
+        // Each region must form exactly one component spanning all its cells.
+        for group in self
+            .groups
+            .iter()
+            .filter(|g| g.group_type == CellGroupType::StandardBlock)
+        {
+            let region = group.id.unwrap_or_default();
+            let mut root = None;
+            for index in group.iter_indexes() {
+                let r = Self::root(&mut parent, *index as usize);
+                match root {
+                    None => root = Some(r),
+                    Some(existing) if existing != r => {
+                        return Err(PartitionError::DisconnectedRegion { region });
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(root) = root {
+                let size = (-parent[root]) as usize;
+                if size != group.len() {
+                    return Err(PartitionError::DisconnectedRegion { region });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Unites the cells `a` and `b` when they share a region, attaching the
+    /// smaller component under the larger (union by size).
+    fn unite(parent: &mut [i32; 81], region_of: &[Option<usize>; 81], a: u8, b: u8) {
+        if region_of[a as usize] != region_of[b as usize] {
+            return;
+        }
+
+        let ra = Self::root(parent, a as usize);
+        let rb = Self::root(parent, b as usize);
+        if ra == rb {
+            return;
+        }
+
+        // `parent[root]` is the negated size, so the more negative one is larger.
+        let (larger, smaller) = if parent[ra] <= parent[rb] {
+            (ra, rb)
+        } else {
+            (rb, ra)
+        };
+        parent[larger] += parent[smaller];
+        parent[smaller] = larger as i32;
+    }
+
+    /// Finds the root of `node` with path compression.
+    fn root(parent: &mut [i32; 81], mut node: usize) -> usize {
+        while parent[node] >= 0 {
+            let grandparent = parent[node] as usize;
+            // Path-halving keeps the tree shallow on repeated lookups.
+            if parent[grandparent] >= 0 {
+                parent[node] = parent[grandparent];
+            }
+            node = parent[node] as usize;
+        }
+        node
+    }
+}
+
+/// An error describing why a set of regions does not form a valid board
+/// partition, see [`CellGroups::validate_partition`].
+#[derive(Debug, thiserror::Error, Eq, PartialEq)]
+pub enum PartitionError {
+    /// A region's cells are not all orthogonally connected.
+    #[error("region {region} is not a single connected blob")]
+    DisconnectedRegion { region: usize },
+    /// A cell was claimed by more than one region.
+    #[error("cell {index} belongs to more than one region")]
+    OverlappingCell { index: u8 },
+    /// A cell was not covered by any region.
+    #[error("cell {index} is not covered by any region")]
+    UncoveredCell { index: u8 },
 }
 
 /// A convenience trait for registering a [`CellGroup`] constructed from an iterator.
@@ -385,6 +637,48 @@ mod tests {
         assert!(!cg.contains(Index::new(1)));
     }
 
+    #[test]
+    fn sudoku_order_four() {
+        // A 4×4 board has 2×2 blocks, so box_side = 2 and side = 4.
+        let groups = CellGroups::default().with_sudoku_order(2);
+
+        // The first block covers the top-left 2×2 corner.
+        let block: Vec<_> = groups
+            .get_groups_at_index(Index::new(0))
+            .unwrap()
+            .into_iter()
+            .find(|g| g.group_type == CellGroupType::StandardBlock)
+            .unwrap()
+            .iter_indexes()
+            .map(|i| *i)
+            .collect();
+        assert_eq!(block, vec![0, 1, 4, 5]);
+
+        // Row 0 spans the first four cells and column 0 every fourth cell.
+        let peers = groups
+            .get_peers_at_index(Index::new(0), CollectIndexes::IncludeSelf)
+            .unwrap();
+        assert!(peers.contains(Index::new(3)));
+        assert!(peers.contains(Index::new(12)));
+    }
+
+    #[test]
+    fn diagonals_compose() {
+        let groups = CellGroups::default()
+            .with_default_sudoku_blocks()
+            .with_default_rows_and_columns()
+            .with_diagonals();
+
+        // The center cell sees both diagonal partners.
+        let peers = groups
+            .get_peers_at_index(Index::new(40), CollectIndexes::IncludeSelf)
+            .unwrap();
+        assert!(peers.contains(Index::new(0)));
+        assert!(peers.contains(Index::new(80)));
+        assert!(peers.contains(Index::new(8)));
+        assert!(peers.contains(Index::new(72)));
+    }
+
     //noinspection DuplicatedCode
     #[test]
     fn add_groups() {
@@ -402,4 +696,43 @@ mod tests {
 
         CellGroups::default().add_group(group_a).add_group(group_b);
     }
+
+    #[test]
+    fn region_map_validates_classic_blocks() {
+        let mut regions = [0u8; 81];
+        for (i, region) in regions.iter_mut().enumerate() {
+            let (x, y) = (i % 9, i / 9);
+            *region = ((y / 3) * 3 + (x / 3)) as u8;
+        }
+
+        let groups = CellGroups::from_region_map(&regions).unwrap();
+        // The top-left region is the classic top-left block.
+        let block: Vec<_> = groups
+            .get_groups_at_index(Index::new(0))
+            .unwrap()
+            .into_iter()
+            .find(|g| g.group_type == CellGroupType::StandardBlock)
+            .unwrap()
+            .iter_indexes()
+            .map(|i| *i)
+            .collect();
+        assert_eq!(block, vec![0, 1, 2, 9, 10, 11, 18, 19, 20]);
+    }
+
+    #[test]
+    fn region_map_rejects_disconnected_region() {
+        // Start from the classic blocks, then swap two far-apart cells between
+        // regions so two regions become disconnected.
+        let mut regions = [0u8; 81];
+        for (i, region) in regions.iter_mut().enumerate() {
+            let (x, y) = (i % 9, i / 9);
+            *region = ((y / 3) * 3 + (x / 3)) as u8;
+        }
+        regions.swap(0, 80);
+
+        assert!(matches!(
+            CellGroups::from_region_map(&regions),
+            Err(PartitionError::DisconnectedRegion { .. })
+        ));
+    }
 }
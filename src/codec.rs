@@ -0,0 +1,266 @@
+//! A compact, copy-pasteable binary codec for puzzles.
+//!
+//! Each cell is packed into a single nibble (value `1..=9`, `0` for empty), so
+//! the 81 cells of a board fit into 41 bytes. A leading header byte records the
+//! board edge length so the format can grow to other variants later. The bytes
+//! are base64-encoded with the URL-safe, unpadded alphabet to yield a
+//! fixed-length token that survives being pasted into a URL.
+
+use crate::cell_group::{CellGroupType, CellGroups};
+use crate::game::Game;
+use crate::index::Index;
+use crate::value::Value;
+use crate::GameState;
+
+/// The board edge length encoded in the header byte.
+const BOARD_WIDTH: u8 = 9;
+
+/// The number of cells on a classic board.
+const CELL_COUNT: usize = 81;
+
+/// The number of bytes needed to pack [`CELL_COUNT`] nibbles.
+const PACKED_LEN: usize = (CELL_COUNT + 1) / 2;
+
+/// An error produced while decoding a puzzle from its string representation.
+#[derive(Debug, thiserror::Error)]
+pub enum PuzzleCodecError {
+    /// The string contained a character outside the base64 alphabet.
+    #[error("the encoded string is not valid base64")]
+    InvalidBase64,
+    /// The decoded payload did not have the expected length.
+    #[error("the encoded payload has an unexpected length")]
+    InvalidLength,
+    /// A packed nibble was outside the valid value range.
+    #[error("the value `{0}` is out of range")]
+    ValueOutOfRange(u8),
+    /// The header advertised a board size or variant that is not supported.
+    #[error("unsupported board header `{0}`")]
+    UnsupportedHeader(u8),
+}
+
+impl GameState {
+    /// Encodes the board as a URL-safe base64 token.
+    pub fn to_base64(&self) -> String {
+        let mut bytes = Vec::with_capacity(1 + PACKED_LEN);
+        bytes.push(BOARD_WIDTH);
+        pack_grid(self, &mut bytes);
+        base64_encode(&bytes)
+    }
+
+    /// Decodes a board previously produced by [`GameState::to_base64`].
+    pub fn from_base64(encoded: &str) -> Result<Self, PuzzleCodecError> {
+        let bytes = base64_decode(encoded)?;
+        if bytes.len() != 1 + PACKED_LEN {
+            return Err(PuzzleCodecError::InvalidLength);
+        }
+        if bytes[0] != BOARD_WIDTH {
+            return Err(PuzzleCodecError::UnsupportedHeader(bytes[0]));
+        }
+        let values = unpack_grid(&bytes[1..])?;
+        Ok(GameState::new_from(values))
+    }
+}
+
+impl Game {
+    /// Encodes the game — board, region assignment and optional solution — as a
+    /// URL-safe base64 token.
+    pub fn to_base64(&self) -> String {
+        let has_solution = self.expected_solution.is_some();
+        let mut bytes = Vec::with_capacity(2 + 3 * PACKED_LEN);
+        bytes.push(BOARD_WIDTH);
+        bytes.push(u8::from(has_solution));
+        pack_grid(&self.initial_state, &mut bytes);
+        pack_nibbles(&region_map(&self.groups), &mut bytes);
+        if let Some(solution) = &self.expected_solution {
+            pack_grid(solution, &mut bytes);
+        }
+        base64_encode(&bytes)
+    }
+
+    /// Decodes a game previously produced by [`Game::to_base64`].
+    pub fn from_base64(encoded: &str) -> Result<Self, PuzzleCodecError> {
+        let bytes = base64_decode(encoded)?;
+        if bytes.len() < 2 {
+            return Err(PuzzleCodecError::InvalidLength);
+        }
+        if bytes[0] != BOARD_WIDTH {
+            return Err(PuzzleCodecError::UnsupportedHeader(bytes[0]));
+        }
+
+        let has_solution = bytes[1] == 1;
+        let expected = 2 + if has_solution { 3 } else { 2 } * PACKED_LEN;
+        if bytes.len() != expected {
+            return Err(PuzzleCodecError::InvalidLength);
+        }
+
+        let grid = &bytes[2..2 + PACKED_LEN];
+        let regions = &bytes[2 + PACKED_LEN..2 + 2 * PACKED_LEN];
+
+        let initial_state = GameState::new_from(unpack_grid(grid)?);
+
+        let region_ids = unpack_nibbles(regions);
+        let groups = CellGroups::from_region_map(&region_ids)
+            .map_err(|_| PuzzleCodecError::InvalidLength)?;
+
+        let expected_solution = if has_solution {
+            let solution = &bytes[2 + 2 * PACKED_LEN..];
+            Some(GameState::new_from(unpack_grid(solution)?))
+        } else {
+            None
+        };
+
+        Ok(Game {
+            initial_state,
+            groups,
+            expected_solution,
+        })
+    }
+}
+
+/// Packs a board's solved-cell values into `out` as nibbles.
+fn pack_grid(state: &GameState, out: &mut Vec<u8>) {
+    let mut nibbles = [0u8; CELL_COUNT];
+    for index in Index::range() {
+        let value = state
+            .get_at_index(index)
+            .as_bitset()
+            .as_single_value()
+            .map_or(0, |v| (*v).get());
+        nibbles[*index as usize] = value;
+    }
+    pack_nibbles(&nibbles, out);
+}
+
+/// Reads the region id of every cell into a paint-by-numbers array.
+fn region_map(groups: &CellGroups) -> [u8; CELL_COUNT] {
+    let mut map = [0u8; CELL_COUNT];
+    for (region, group) in groups
+        .iter()
+        .filter(|g| g.group_type == CellGroupType::StandardBlock)
+        .enumerate()
+    {
+        for index in group.iter_indexes() {
+            map[*index as usize] = region as u8;
+        }
+    }
+    map
+}
+
+/// Packs a slice of nibbles (two per byte) into `out`.
+fn pack_nibbles(nibbles: &[u8], out: &mut Vec<u8>) {
+    for pair in nibbles.chunks(2) {
+        let high = pair[0] << 4;
+        let low = pair.get(1).copied().unwrap_or(0);
+        out.push(high | low);
+    }
+}
+
+/// Unpacks [`CELL_COUNT`] nibbles from a packed byte slice.
+fn unpack_nibbles(bytes: &[u8]) -> [u8; CELL_COUNT] {
+    let mut nibbles = [0u8; CELL_COUNT];
+    for (i, nibble) in nibbles.iter_mut().enumerate() {
+        let byte = bytes[i / 2];
+        *nibble = if i % 2 == 0 { byte >> 4 } else { byte & 0x0F };
+    }
+    nibbles
+}
+
+/// Unpacks a packed grid into a value array, validating the range.
+fn unpack_grid(bytes: &[u8]) -> Result<[u8; CELL_COUNT], PuzzleCodecError> {
+    let nibbles = unpack_nibbles(bytes);
+    for &nibble in &nibbles {
+        if nibble > 9 {
+            return Err(PuzzleCodecError::ValueOutOfRange(nibble));
+        }
+    }
+    // Touch the Value type so a later widening of the range is caught here too.
+    debug_assert!(Value::try_from(9).is_ok());
+    Ok(nibbles)
+}
+
+/// The URL-safe base64 alphabet (RFC 4648 §5), used without padding.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        out.push(ALPHABET[(triple >> 18 & 0x3F) as usize] as char);
+        out.push(ALPHABET[(triple >> 12 & 0x3F) as usize] as char);
+        if chunk.len() > 1 {
+            out.push(ALPHABET[(triple >> 6 & 0x3F) as usize] as char);
+        }
+        if chunk.len() > 2 {
+            out.push(ALPHABET[(triple & 0x3F) as usize] as char);
+        }
+    }
+    out
+}
+
+fn base64_decode(encoded: &str) -> Result<Vec<u8>, PuzzleCodecError> {
+    let decode_char = |c: u8| -> Result<u32, PuzzleCodecError> {
+        ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .map(|p| p as u32)
+            .ok_or(PuzzleCodecError::InvalidBase64)
+    };
+
+    let chars = encoded.as_bytes();
+    let mut out = Vec::with_capacity(encoded.len() / 4 * 3);
+    for chunk in chars.chunks(4) {
+        if chunk.len() < 2 {
+            return Err(PuzzleCodecError::InvalidBase64);
+        }
+        let mut triple = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            triple |= decode_char(c)? << (18 - 6 * i);
+        }
+        out.push((triple >> 16 & 0xFF) as u8);
+        if chunk.len() > 2 {
+            out.push((triple >> 8 & 0xFF) as u8);
+        }
+        if chunk.len() > 3 {
+            out.push((triple & 0xFF) as u8);
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_state_round_trips() {
+        let state = crate::example_games::sudoku::example_sudoku().initial_state;
+        let token = state.to_base64();
+        let decoded = GameState::from_base64(&token).unwrap();
+        assert_eq!(decoded, state);
+    }
+
+    #[test]
+    fn game_round_trips_with_solution() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let token = game.to_base64();
+        let decoded = Game::from_base64(&token).unwrap();
+        assert_eq!(decoded.initial_state, game.initial_state);
+        assert_eq!(decoded.expected_solution, game.expected_solution);
+    }
+
+    #[test]
+    fn rejects_malformed_token() {
+        assert!(matches!(
+            GameState::from_base64("!!!!"),
+            Err(PuzzleCodecError::InvalidBase64)
+        ));
+        assert!(matches!(
+            GameState::from_base64("AAAA"),
+            Err(PuzzleCodecError::InvalidLength)
+        ));
+    }
+}
@@ -9,8 +9,12 @@ pub struct Coordinate {
 }
 
 impl Coordinate {
-    pub const GAME_WIDTH: u8 = 9;
-    pub const GAME_HEIGHT: u8 = 9;
+    /// The board edge length, derived from the number of symbols so that other
+    /// board sizes only have to change [`DEFAULT_SYMBOL_COUNT`].
+    ///
+    /// [`DEFAULT_SYMBOL_COUNT`]: crate::value::DEFAULT_SYMBOL_COUNT
+    pub const GAME_WIDTH: u8 = crate::value::DEFAULT_SYMBOL_COUNT as u8;
+    pub const GAME_HEIGHT: u8 = crate::value::DEFAULT_SYMBOL_COUNT as u8;
 
     pub const fn new(x: u8, y: u8) -> Self {
         debug_assert!(x < Self::GAME_WIDTH && y < Self::GAME_HEIGHT);
@@ -1,25 +1,220 @@
-use crate::cell_group::{CellGroups, CollectIndexes};
+use crate::cell_group::CellGroups;
 use crate::game_state::InvalidGameState;
 use crate::index::Index;
+use crate::candidate_ranking::CandidateRanking;
 use crate::state_stack::{StateStack, StateStackEntry};
 use crate::strategies::{
-    HiddenSingles, HiddenTwins, NakedSingles, NakedTwins, Strategy, StrategyResult, XWing,
+    BoxLine, BoxLineReduction, Difficulty, HiddenSingles, HiddenSubset, ImplicationChains,
+    IntersectionRemoval, LineBoxReduction, NakedSingles, Fish, NakedSubset, Pointing,
+    SimpleColoring, Strategy, StrategyResult, WxyzWing, XWing,
 };
+use crate::game_state::SolutionRate;
 use crate::GameState;
 use log::{debug, trace};
 
 type PrintFn = fn(state: &GameState) -> ();
 
+/// A callback invoked on every branch pop with the current solution rate,
+/// the queue depth and the number of forks made so far.
+type ProgressFn = fn(rate: SolutionRate, depth: usize, forks: usize) -> ();
+
 pub struct DefaultSolver {
     groups: CellGroups,
     print_fn: Option<PrintFn>,
+    progress_fn: Option<ProgressFn>,
     strategies: Vec<Box<dyn Strategy>>,
+    /// Whether the branching search deduplicates already-seen boards.
+    transposition_table: bool,
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error("The game is unsolvable")]
 pub struct Unsolvable(pub GameState);
 
+/// The outcome of a solution-uniqueness check, see
+/// [`DefaultSolver::solve_unique`].
+#[derive(Debug, Clone)]
+pub enum Solutions {
+    /// The board has no solution.
+    Unsolvable,
+    /// The board has exactly one solution (a *proper* puzzle).
+    UniqueSolution(GameState),
+    /// The board has at least two solutions; two distinct witnesses are given.
+    MultipleSolutions(GameState, GameState),
+}
+
+/// Records how often a single technique fired during a solve.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TechniqueCount {
+    /// The name of the strategy, e.g. `"Naked singles"`.
+    pub name: String,
+    /// The difficulty level the technique contributes.
+    pub difficulty: Difficulty,
+    /// How many times the technique successfully applied a change.
+    pub count: usize,
+}
+
+/// How a [`SolverStep`] was derived, used to weight the difficulty score.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StepKind {
+    /// A naked or hidden single placement — the cheapest deduction.
+    Trivial,
+    /// A named logical strategy (subsets, fish, coloring, ...) fired.
+    Logic,
+    /// A guess made in the forking step — the most expensive step.
+    Probe,
+}
+
+/// A single structured entry in a [`SolveReport`]'s audit trail, capturing one
+/// successful strategy application or branch event.
+#[derive(Debug, Clone)]
+pub struct SolverStep {
+    /// How this step was derived.
+    pub kind: StepKind,
+    /// The name of the strategy that fired, or `"Fork"`/`"Backtrack"` for a
+    /// branch event.
+    pub strategy: String,
+    /// The difficulty the step contributes to the grade.
+    pub difficulty: Difficulty,
+    /// The number of cells solved by this step.
+    pub placements: usize,
+    /// The number of candidates eliminated by this step.
+    pub eliminations: usize,
+    /// The branch this step was recorded on (`0` for the root branch).
+    pub branch: usize,
+}
+
+/// A summary of how a board was solved.
+///
+/// Besides the final verdict (solved and/or consistent), this records the
+/// number of given clues, which techniques fired (and how often), a structured
+/// and human-readable audit trail and an overall difficulty grade derived from
+/// the hardest technique required.
+#[derive(Debug, Clone)]
+pub struct SolveReport {
+    /// The solved (or last-seen) board state.
+    pub state: GameState,
+    /// Whether the board was fully solved.
+    pub is_solved: bool,
+    /// Whether the board state is still consistent with the game rules.
+    pub is_valid: bool,
+    /// The number of cells that were given as clues in the initial board.
+    pub given: usize,
+    /// The per-technique application counts, in the order they first fired.
+    pub technique_counts: Vec<TechniqueCount>,
+    /// A human-readable, ordered audit trail of the deductions that were made.
+    pub trail: Vec<String>,
+    /// The structured, ordered audit trail of deductions and branch events.
+    pub steps: Vec<SolverStep>,
+    /// The difficulty grade, i.e. the hardest technique that was required.
+    pub difficulty: Option<Difficulty>,
+}
+
+impl SolveReport {
+    /// Registers a successful application of the named technique, appending a
+    /// structured [`SolverStep`] and a line to the human-readable audit trail.
+    fn record(
+        &mut self,
+        name: String,
+        difficulty: Difficulty,
+        placements: usize,
+        eliminations: usize,
+        branch: usize,
+    ) {
+        let occurrence = match self.technique_counts.iter_mut().find(|e| e.name == name) {
+            Some(entry) => {
+                entry.count += 1;
+                entry.count
+            }
+            None => {
+                self.technique_counts.push(TechniqueCount {
+                    name: name.clone(),
+                    difficulty,
+                    count: 1,
+                });
+                1
+            }
+        };
+
+        self.trail.push(format!(
+            "{step}. {name} applied a deduction ({placements} placed, {eliminations} eliminated, occurrence #{occurrence})",
+            step = self.trail.len() + 1,
+            name = name,
+            placements = placements,
+            eliminations = eliminations,
+            occurrence = occurrence
+        ));
+        // Single-cell placements are the trivial bread-and-butter deductions;
+        // everything else is a genuine logical technique.
+        let kind = if difficulty <= Difficulty::Easy {
+            StepKind::Trivial
+        } else {
+            StepKind::Logic
+        };
+        self.steps.push(SolverStep {
+            kind,
+            strategy: name,
+            difficulty,
+            placements,
+            eliminations,
+            branch,
+        });
+        self.difficulty = Some(self.difficulty.map_or(difficulty, |d| d.max(difficulty)));
+    }
+
+    /// Records a branch event (a fork or a backtrack) in the audit trail.
+    fn record_branch(&mut self, kind: &'static str, branch: usize) {
+        self.trail.push(format!(
+            "{step}. {kind} on branch {branch}",
+            step = self.trail.len() + 1,
+            kind = kind,
+            branch = branch
+        ));
+        self.steps.push(SolverStep {
+            kind: StepKind::Probe,
+            strategy: kind.to_string(),
+            difficulty: Difficulty::Fiendish,
+            placements: 0,
+            eliminations: 0,
+            branch,
+        });
+    }
+
+    /// A numeric difficulty score that weights each recorded step by its
+    /// strategy cost: cheap logic (naked singles) contributes little, fish and
+    /// chaining techniques more, and branch events (guessing) the most.
+    pub fn score(&self) -> usize {
+        self.steps
+            .iter()
+            .map(|step| match step.kind {
+                // Trivial placements barely move the needle.
+                StepKind::Trivial => 1,
+                // Logical techniques cost their difficulty level.
+                StepKind::Logic => step.difficulty as usize,
+                // Guessing is by far the most expensive thing the solver does.
+                StepKind::Probe => (step.difficulty as usize) * 10,
+            })
+            .sum()
+    }
+
+    /// Whether the board could only be finished by guessing, i.e. the logical
+    /// strategies stalled and the solver fell back to the branching search.
+    pub fn required_search(&self) -> bool {
+        self.steps.iter().any(|step| step.kind == StepKind::Probe)
+    }
+
+    /// The number of recorded steps of a given [`StepKind`], letting callers
+    /// tell deduced placements apart from guessed ones.
+    pub fn count_of(&self, kind: StepKind) -> usize {
+        self.steps.iter().filter(|step| step.kind == kind).count()
+    }
+
+    /// Renders the audit trail as a single newline-separated string.
+    pub fn describe(&self) -> String {
+        self.trail.join("\n")
+    }
+}
+
 #[derive(Debug)]
 struct SmallestIndex {
     pub index: Index,
@@ -39,7 +234,25 @@ pub struct DefaultSolverConfig {
     pub hidden_singles: bool,
     pub naked_twins: bool,
     pub hidden_twins: bool,
+    pub naked_subsets: bool,
+    pub hidden_subsets: bool,
+    pub pointing: bool,
+    pub box_line_reduction: bool,
+    /// The combined, group-aware box/line reduction (both directions at once).
+    pub box_line_reduction_combined: bool,
+    pub box_line: bool,
+    pub intersection_removal: bool,
     pub xwings: bool,
+    /// The standalone X-Wing strategy; the generalized [`xwings`](Self::xwings)
+    /// fish pass already covers order-2 fish, so this is opt-in.
+    pub xwing: bool,
+    pub wxyz_wings: bool,
+    pub simple_coloring: bool,
+    /// Forcing chains via binary-implication propagation. Expensive, so opt-in.
+    pub implication_chains: bool,
+    /// Whether to prune already-visited boards during the branching search.
+    /// Disable this to measure the raw number of branches explored.
+    pub transposition_table: bool,
 }
 
 impl Default for DefaultSolverConfig {
@@ -48,7 +261,23 @@ impl Default for DefaultSolverConfig {
             hidden_singles: true,
             naked_twins: true,
             hidden_twins: true,
+            naked_subsets: true,
+            hidden_subsets: true,
+            // Superseded by the combined intersection-removal pass below; kept
+            // as opt-in switches for the single-direction strategies and the
+            // combined box/line reduction.
+            pointing: false,
+            box_line_reduction: false,
+            box_line_reduction_combined: false,
+            // Superseded by the combined intersection-removal pass; opt-in.
+            box_line: false,
+            intersection_removal: true,
             xwings: true,
+            xwing: false,
+            wxyz_wings: true,
+            simple_coloring: true,
+            implication_chains: false,
+            transposition_table: true,
         }
     }
 }
@@ -62,15 +291,35 @@ impl DefaultSolver {
         let strategies: Vec<Box<dyn Strategy>> = vec![
             NakedSingles::new_box(),
             HiddenSingles::new_box(config.hidden_singles),
-            NakedTwins::new_box(config.naked_twins),
-            HiddenTwins::new_box(config.hidden_twins),
-            XWing::new_box(config.xwings),
+            // Naked/hidden twins are just subsets of size two.
+            NakedSubset::new_box(2, config.naked_twins),
+            HiddenSubset::new_box(2, config.hidden_twins),
+            // Naked/hidden triples and quads via the generalized subset finder.
+            NakedSubset::new_box(3, config.naked_subsets),
+            NakedSubset::new_box(4, config.naked_subsets),
+            HiddenSubset::new_box(3, config.hidden_subsets),
+            HiddenSubset::new_box(4, config.hidden_subsets),
+            BoxLine::new_box(config.box_line),
+            IntersectionRemoval::new_box(config.intersection_removal),
+            Pointing::new_box(config.pointing),
+            LineBoxReduction::new_box(config.box_line_reduction),
+            BoxLineReduction::new_box(config.box_line_reduction_combined),
+            // The X-Wing, Swordfish and Jellyfish are fish of orders 2, 3 and 4.
+            Fish::new_box(2, config.xwings),
+            Fish::new_box(3, config.xwings),
+            Fish::new_box(4, config.xwings),
+            XWing::new_box(config.xwing),
+            WxyzWing::new_box(config.wxyz_wings),
+            SimpleColoring::new_box(config.simple_coloring),
+            ImplicationChains::new_box(config.implication_chains),
         ];
 
         Self {
             groups: groups.as_ref().clone(),
             print_fn: None,
+            progress_fn: None,
             strategies,
+            transposition_table: config.transposition_table,
         }
     }
 
@@ -78,11 +327,176 @@ impl DefaultSolver {
         self.print_fn = Some(print_fn);
     }
 
+    /// Registers a callback invoked on every branch pop, letting embedders
+    /// drive a progress bar or abort a pathological search.
+    pub fn set_progress_fn(&mut self, progress_fn: ProgressFn) {
+        self.progress_fn = Some(progress_fn);
+    }
+
     pub fn solve<S: AsRef<GameState>>(&self, state: S) -> Result<GameState, Unsolvable> {
+        self.solve_inner(state.as_ref()).0
+    }
+
+    /// Solves the board and returns a [`SolveReport`] describing which
+    /// techniques fired, how often, and the resulting difficulty grade.
+    pub fn solve_report<S: AsRef<GameState>>(&self, state: S) -> SolveReport {
+        self.solve_inner(state.as_ref()).1
+    }
+
+    /// Counts the number of distinct solutions of the board, stopping once
+    /// `limit` solutions have been found.
+    ///
+    /// Passing a `limit` of `2` is enough to decide uniqueness cheaply, see
+    /// [`DefaultSolver::has_unique_solution`].
+    pub fn count_solutions<S: AsRef<GameState>>(&self, state: S, limit: usize) -> usize {
+        let mut count = 0;
+        self.enumerate(state.as_ref().clone(), limit, &mut count);
+        count
+    }
+
+    /// Determines whether the board has exactly one solution, i.e. whether it
+    /// is a *proper* puzzle rather than an ambiguous one.
+    pub fn has_unique_solution<S: AsRef<GameState>>(&self, state: S) -> bool {
+        self.count_solutions(state, 2) == 1
+    }
+
+    /// Runs the full search and classifies the board as having no, exactly one,
+    /// or more than one solution, short-circuiting once a second distinct
+    /// solution is found.
+    pub fn solve_unique<S: AsRef<GameState>>(&self, state: S) -> Solutions {
+        let mut solutions = Vec::new();
+        self.collect_solutions(state.as_ref().clone(), 2, &mut solutions);
+        match solutions.len() {
+            0 => Solutions::Unsolvable,
+            1 => Solutions::UniqueSolution(solutions.pop().unwrap()),
+            _ => {
+                let second = solutions.pop().unwrap();
+                let first = solutions.pop().unwrap();
+                Solutions::MultipleSolutions(first, second)
+            }
+        }
+    }
+
+    /// Enumerates every distinct solution of the board.
+    pub fn solve_all<S: AsRef<GameState>>(&self, state: S) -> Vec<GameState> {
+        let mut solutions = Vec::new();
+        self.collect_solutions(state.as_ref().clone(), usize::MAX, &mut solutions);
+        solutions
+    }
+
+    /// Collects up to `limit` distinct solutions, deduplicated by fingerprint.
+    fn collect_solutions(&self, state: GameState, limit: usize, out: &mut Vec<GameState>) {
+        if out.len() >= limit {
+            return;
+        }
+
+        if !state.is_consistent(&self.groups) {
+            return;
+        }
+
+        if self.reduce(&state).is_err() || !state.is_consistent(&self.groups) {
+            return;
+        }
+
+        if state.is_solved(&self.groups) {
+            let fingerprint = state.fingerprint();
+            if !out.iter().any(|s| s.fingerprint() == fingerprint) {
+                out.push(state);
+            }
+            return;
+        }
+
+        let index = match self.pick_index_to_fork_from(&state) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let candidates: Vec<_> = state.get_at_index(index).iter_candidates().collect();
+        for value in candidates {
+            if out.len() >= limit {
+                return;
+            }
+
+            let forked = state.clone();
+            forked.place_and_propagate_at_index(index, value, &self.groups);
+            self.collect_solutions(forked, limit, out);
+        }
+    }
+
+    fn enumerate(&self, state: GameState, limit: usize, count: &mut usize) {
+        if *count >= limit {
+            return;
+        }
+
+        if !state.is_consistent(&self.groups) {
+            return;
+        }
+
+        // Apply the logical strategies to reduce the board before branching.
+        if self.reduce(&state).is_err() || !state.is_consistent(&self.groups) {
+            return;
+        }
+
+        if state.is_solved(&self.groups) {
+            *count += 1;
+            return;
+        }
+
+        let index = match self.pick_index_to_fork_from(&state) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let candidates: Vec<_> = state.get_at_index(index).iter_candidates().collect();
+        for value in candidates {
+            if *count >= limit {
+                return;
+            }
+
+            let forked = state.clone();
+            forked.place_and_propagate_at_index(index, value, &self.groups);
+            self.enumerate(forked, limit, count);
+        }
+    }
+
+    /// Applies the logical strategies without recording an audit trail.
+    fn reduce(&self, state: &GameState) -> Result<(), InvalidGameState> {
+        let mut scratch = SolveReport {
+            state: state.clone(),
+            is_solved: false,
+            is_valid: true,
+            given: 0,
+            technique_counts: Vec::new(),
+            trail: Vec::new(),
+            steps: Vec::new(),
+            difficulty: None,
+        };
+        self.apply_strategies(state, &mut scratch, 0)
+    }
+
+    fn solve_inner(&self, initial: &GameState) -> (Result<GameState, Unsolvable>, SolveReport) {
+        let given = initial
+            .iter()
+            .filter(|cell| cell.is_solved())
+            .count();
+        let mut report = SolveReport {
+            state: initial.clone(),
+            is_solved: false,
+            is_valid: true,
+            given,
+            technique_counts: Vec::new(),
+            trail: Vec::new(),
+            steps: Vec::new(),
+            difficulty: None,
+        };
+
         // We keep the last seen state as a reference to return when the board is unsolvable.
-        let mut last_seen_state = state.as_ref().clone();
+        let mut last_seen_state = initial.clone();
 
-        let mut stack = StateStack::new_with(last_seen_state.clone());
+        // The stack acts as a transposition table, pruning branch states whose
+        // board has already been enqueued.
+        let mut stack =
+            StateStack::new_with_dedup(last_seen_state.clone(), self.transposition_table);
         'stack: while let Some(StateStackEntry {
             branch_id: fork_id,
             state,
@@ -90,6 +504,10 @@ impl DefaultSolver {
         {
             last_seen_state = state.clone();
 
+            if let Some(progress_fn) = self.progress_fn {
+                progress_fn(state.solution_rate(), stack.len(), stack.num_forks());
+            }
+
             debug!(
                 "Processing state {id} (queue depth: {depth}/{max_depth}, num forks: {num_forks}) ...",
                 id = fork_id,
@@ -101,18 +519,25 @@ impl DefaultSolver {
 
             if state.is_solved(&self.groups) {
                 debug!("Branch {id} is solved", id = fork_id);
-                return Ok(state);
+                report.state = state.clone();
+                report.is_solved = true;
+                return (Ok(state), report);
             }
 
             // Early exit the branch if needed.
             if !state.is_consistent(&self.groups) {
                 debug!("Branch is inconsistent - ignoring");
+                report.record_branch("Backtrack", fork_id.id());
                 continue;
             }
 
-            if self.apply_strategies(&state).is_err() {
+            if self
+                .apply_strategies(&state, &mut report, fork_id.id())
+                .is_err()
+            {
                 debug!("Applying strategies resulted in inconsistent state - ignoring branch");
                 self.print_state(&state);
+                report.record_branch("Backtrack", fork_id.id());
                 continue 'stack;
             }
 
@@ -120,7 +545,9 @@ impl DefaultSolver {
 
             if state.is_solved(&self.groups) {
                 debug!("Applying strategies solved branch {id}", id = fork_id);
-                return Ok(state);
+                report.state = state.clone();
+                report.is_solved = true;
+                return (Ok(state), report);
             }
 
             let fork_index = match self.pick_index_to_fork_from(&state) {
@@ -136,8 +563,14 @@ impl DefaultSolver {
             debug_assert!(!fork_cell.is_impossible());
             debug_assert!(!fork_cell.is_solved());
 
-            // Pick an arbitrary value to fork the state from.
-            let fork_value = fork_cell.iter_candidates().next().unwrap();
+            // Prefer the highest-confidence candidate for this cell as ranked by
+            // the probabilistic estimator; fall back to the first candidate.
+            let fork_value = CandidateRanking::rank(&state, &self.groups)
+                .into_iter()
+                .find(|a| a.index == fork_index)
+                .map(|a| a.value)
+                .filter(|v| fork_cell.contains(*v))
+                .unwrap_or_else(|| fork_cell.iter_candidates().next().unwrap());
 
             // Fork the board.
             debug!(
@@ -147,6 +580,7 @@ impl DefaultSolver {
             );
             let forked = state.clone();
             forked.place_and_propagate_at_index(fork_index, fork_value, &self.groups);
+            report.record_branch("Fork", fork_id.id());
 
             // In the current version of the board, simply forget the picked option.
             state.forget_at_index(fork_index, fork_value);
@@ -164,16 +598,41 @@ impl DefaultSolver {
             }
         }
 
-        Err(Unsolvable(last_seen_state))
+        report.state = last_seen_state.clone();
+        report.is_valid = last_seen_state.is_consistent(&self.groups);
+        (Err(Unsolvable(last_seen_state)), report)
     }
 
     /// Applies different strategies for solving the board without branching.
-    fn apply_strategies(&self, state: &GameState) -> Result<(), InvalidGameState> {
+    fn apply_strategies(
+        &self,
+        state: &GameState,
+        report: &mut SolveReport,
+        branch: usize,
+    ) -> Result<(), InvalidGameState> {
         'solving: loop {
             'next_strategy: for strategy in self.strategies.iter().filter(|&s| s.is_enabled()) {
+                // Snapshot the board so we can attribute placements and
+                // eliminations to the strategy that fired.
+                let solved_before = state.solved_count();
+                let candidates_before = state.total_candidates();
+
                 match strategy.apply(&state, &self.groups) {
                     Err(e) => return Err(e),
                     Ok(outcome) => {
+                        if outcome == StrategyResult::AppliedChange {
+                            let placements = state.solved_count().saturating_sub(solved_before);
+                            let eliminations =
+                                candidates_before.saturating_sub(state.total_candidates());
+                            report.record(
+                                strategy.name(),
+                                strategy.difficulty(),
+                                placements,
+                                eliminations,
+                                branch,
+                            );
+                        }
+
                         #[cfg(debug_assertions)]
                         {
                             if !state.is_consistent(&self.groups) {
@@ -213,43 +672,29 @@ impl DefaultSolver {
         }
     }
 
+    /// Picks the cell to branch on using the minimum-remaining-values (MRV)
+    /// heuristic: the unsolved cell with the fewest remaining candidates.
+    ///
+    /// Branching on the most constrained cell keeps the search tree narrow and
+    /// surfaces contradictions earlier.
     fn pick_index_to_fork_from(&self, state: &GameState) -> Option<Index> {
-        // Identify the group with the fewest candidates.
-        // Within that, identify the cell with the fewest options in that group.
         let mut smallest = SmallestIndex::default();
 
-        for index_under_test in Index::range() {
-            let mut group_size = 0;
-            let mut group_smallest = SmallestIndex::default();
-            for index in self
-                .groups
-                .get_peers_at_index(index_under_test, CollectIndexes::IncludeSelf)
-                .unwrap()
-                .iter()
-            {
-                let index_size = state.get_at_index(index).len();
-
-                // Ignore solved or invalid cells.
-                if index_size <= 1 {
-                    continue;
-                }
+        for index in Index::range() {
+            let size = state.get_at_index(index).len();
 
-                // Accumulate the group size and keep track of the smallest index
-                // within that group.
-                group_size += index_size;
-                if index_size < group_smallest.size {
-                    group_smallest = SmallestIndex {
-                        index,
-                        size: index_size,
-                    }
-                }
+            // Ignore solved or invalid cells.
+            if size <= 1 {
+                continue;
             }
 
-            if group_size < smallest.size && group_size > 0 {
-                smallest = SmallestIndex {
-                    index: group_smallest.index,
-                    size: group_size,
-                };
+            if size < smallest.size {
+                smallest = SmallestIndex { index, size };
+
+                // A bi-value cell is the tightest possible branch.
+                if size == 2 {
+                    break;
+                }
             }
         }
 
@@ -286,6 +731,76 @@ mod tests {
         assert!(solution.is_solved(&game.groups));
     }
 
+    #[test]
+    fn solve_report_grades_sudoku() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let solver = DefaultSolver::new(&game);
+        let report = solver.solve_report(&game.initial_state);
+
+        assert!(report.is_solved);
+        assert!(report.is_valid);
+        assert!(report.given > 0 && report.given < 81);
+        assert!(!report.technique_counts.is_empty());
+        assert!(report.difficulty.is_some());
+        assert_eq!(report.trail.len(), report.technique_counts.iter().map(|t| t.count).sum());
+        assert!(!report.describe().is_empty());
+    }
+
+    #[test]
+    fn report_distinguishes_deduced_from_guessed() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let solver = DefaultSolver::new(&game);
+
+        // The example board is solvable by logic alone — no guessing required.
+        let report = solver.solve_report(&game.initial_state);
+        assert!(report.is_solved);
+        assert!(!report.required_search());
+        assert_eq!(report.count_of(StepKind::Probe), 0);
+
+        // The empty board can only be finished by branching.
+        let empty = solver.solve_report(&GameState::new());
+        assert!(empty.required_search());
+        assert!(empty.count_of(StepKind::Probe) > 0);
+    }
+
+    #[test]
+    fn example_sudoku_is_unique() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let solver = DefaultSolver::new(&game);
+        assert_eq!(solver.count_solutions(&game.initial_state, 5), 1);
+        assert!(solver.has_unique_solution(&game.initial_state));
+    }
+
+    #[test]
+    fn solve_unique_identifies_proper_puzzle() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let solver = DefaultSolver::new(&game);
+        match solver.solve_unique(&game.initial_state) {
+            Solutions::UniqueSolution(state) => assert!(state.is_solved(&game.groups)),
+            other => panic!("expected a unique solution, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn solve_unique_detects_ambiguity() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let solver = DefaultSolver::new(&game);
+        let empty = GameState::new();
+        match solver.solve_unique(&empty) {
+            Solutions::MultipleSolutions(a, b) => assert_ne!(a, b),
+            other => panic!("expected multiple solutions, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn empty_board_is_ambiguous() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let solver = DefaultSolver::new(&game);
+        let empty = GameState::new();
+        assert!(solver.count_solutions(&empty, 2) >= 2);
+        assert!(!solver.has_unique_solution(&empty));
+    }
+
     #[test]
     fn solving_sudoku_with_hidden_singles() {
         let game = crate::example_games::sudoku2::example_sudoku();
@@ -1,18 +1,51 @@
 use crate::cell_group::{CellGroups, CollectIndexes};
 use crate::game_cell::GameCell;
 use crate::index::{Index, IndexBitSet};
-use crate::value::{IntoValueOptions, Value, ValueBitSet};
+use crate::value::{IntoValueOptions, Value, ValueBitSet, ValueOption};
 use crate::{Coordinate, IndexedGameCell};
 use std::cell::Cell;
-use std::mem::MaybeUninit;
 
 #[derive(Debug, thiserror::Error)]
 #[error("An invalid game state was reached")]
 pub struct InvalidGameState {}
 
+/// An error produced while parsing a [`GameState`] from its string representation.
+#[derive(Debug, thiserror::Error)]
+pub enum PuzzleParseError {
+    /// A token could not be interpreted as a cell value.
+    #[error("The token `{0}` is not a valid cell value")]
+    InvalidToken(String),
+    /// The line described fewer than 81 cells.
+    #[error("Expected 81 cells but only found {0}")]
+    TooFewCells(usize),
+    /// The line described more than 81 cells.
+    #[error("Expected 81 cells but found more")]
+    TooManyCells,
+}
+
+/// The number of cells on a classic 9×9 board.
+pub const DEFAULT_CELL_COUNT: usize = 81;
+
+/// A lightweight measure of how close a board is to being solved, returned by
+/// [`GameState::solution_rate`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SolutionRate {
+    /// The fraction of cells already solved, from `0.0` to `1.0`.
+    pub solved_fraction: f32,
+    /// The total number of candidates still remaining across the board.
+    pub remaining_candidates: usize,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct GameState {
-    cells: [Cell<GameCell>; 81],
+    /// The cells of the board.
+    ///
+    /// The storage is sized dynamically so that boards other than the classic
+    /// 9×9 grid can be represented; [`GameState::new`] allocates the default
+    /// [`DEFAULT_CELL_COUNT`] cells. Note that the [`Index`]/[`Coordinate`]
+    /// helpers still assume a 9-wide grid, so boards of a different order are
+    /// only fully usable once those are widened as well.
+    cells: Vec<Cell<GameCell>>,
 }
 
 impl AsRef<GameState> for &GameState {
@@ -23,30 +56,193 @@ impl AsRef<GameState> for &GameState {
 
 impl GameState {
     pub fn new() -> Self {
-        let mut cells: [MaybeUninit<Cell<GameCell>>; 81] =
-            unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..81 {
-            cells[i].write(Cell::new(GameCell::default()));
-        }
+        Self::new_sized(DEFAULT_CELL_COUNT)
+    }
+
+    /// Creates an empty board with `cells` cells, all open.
+    pub fn new_sized(cells: usize) -> Self {
         Self {
-            cells: unsafe { std::mem::transmute(cells) },
+            cells: (0..cells).map(|_| Cell::new(GameCell::default())).collect(),
         }
     }
 
     pub fn new_from<S: IntoValueOptions>(values: S) -> Self {
-        let mut cells: [MaybeUninit<Cell<GameCell>>; 81] =
-            unsafe { MaybeUninit::uninit().assume_init() };
-
         let values = values.into();
-        for i in 0..81 {
-            match values[i] {
-                Some(value) => cells[i].write(Cell::new(GameCell::from_value(value))),
-                None => cells[i].write(Cell::new(GameCell::default())),
-            };
+        let cells = values
+            .iter()
+            .map(|value| match value {
+                Some(value) => Cell::new(GameCell::from_value(*value)),
+                None => Cell::new(GameCell::default()),
+            })
+            .collect();
+        Self { cells }
+    }
+
+    /// The number of cells on this board.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cells that are solved (exactly one candidate).
+    #[inline]
+    pub fn solved_count(&self) -> usize {
+        self.iter().filter(|cell| cell.is_solved()).count()
+    }
+
+    /// The fraction of solved cells, ranging from `0.0` (empty) to `1.0`
+    /// (fully solved).
+    #[inline]
+    pub fn progress(&self) -> f32 {
+        if self.cells.is_empty() {
+            return 1.0;
         }
-        Self {
-            cells: unsafe { std::mem::transmute(cells) },
+        self.solved_count() as f32 / self.cells.len() as f32
+    }
+
+    /// The total number of candidates remaining across all cells.
+    ///
+    /// A fully solved board has exactly one candidate per cell; larger values
+    /// indicate how much work remains.
+    #[inline]
+    pub fn total_candidates(&self) -> usize {
+        self.iter().map(|cell| cell.len()).sum()
+    }
+
+    /// A snapshot of how far the board is from a solution, combining the
+    /// fraction of solved cells with the total number of remaining candidates.
+    #[inline]
+    pub fn solution_rate(&self) -> SolutionRate {
+        SolutionRate {
+            solved_fraction: self.progress(),
+            remaining_candidates: self.total_candidates(),
+        }
+    }
+
+    /// Reports the remaining candidates for every cell, indexed by [`Index`].
+    pub fn candidate_grid(&self) -> Vec<ValueBitSet> {
+        self.iter().map(|cell| cell.to_bitset()).collect()
+    }
+
+    /// Computes a hash of the full candidate configuration of the board.
+    ///
+    /// Unlike [`GameState::to_line`], which only captures solved cells, this
+    /// fingerprint reflects every cell's remaining candidates and is therefore
+    /// suitable as a key for deduplicating branch states during search.
+    pub fn fingerprint(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for cell in self.iter() {
+            let mut bits: u16 = 0;
+            for value in cell.iter_candidates() {
+                bits |= 1u16 << ((*value).get() - 1);
+            }
+            bits.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Parses a [`GameState`] from a flat string representation.
+    ///
+    /// Three common formats are accepted and auto-detected:
+    ///
+    /// * A whitespace-separated grid of digits, e.g. `"7 0 6 3 0 8 ..."`,
+    ///   where `0` or `.` denote a blank cell.
+    /// * An 81-character single-line string, e.g. `"706308009..."`, again
+    ///   using `0` or `.` for blanks.
+    /// * The ksudoku-style run encoding where `_` is a blank and the
+    ///   consecutive letters `b, c, d, …` map to the values `1, 2, 3, …`.
+    ///
+    /// The counterpart [`GameState::to_line`] emits the compact 81-character
+    /// form, so `from_line(to_line())` round-trips.
+    pub fn from_line<S: AsRef<str>>(line: S) -> Result<Self, PuzzleParseError> {
+        let line = line.as_ref();
+        let mut values = [None; 81];
+
+        if line.split_whitespace().count() > 1 {
+            // Whitespace-separated digit grid.
+            let mut count = 0;
+            for token in line.split_whitespace() {
+                if count >= 81 {
+                    return Err(PuzzleParseError::TooManyCells);
+                }
+                values[count] = Self::parse_digit(token)?;
+                count += 1;
+            }
+            if count != 81 {
+                return Err(PuzzleParseError::TooFewCells(count));
+            }
+        } else {
+            // Single contiguous string, either digits or the ksudoku run encoding.
+            let mut count = 0;
+            for c in line.chars() {
+                if c.is_whitespace() {
+                    continue;
+                }
+                if count >= 81 {
+                    return Err(PuzzleParseError::TooManyCells);
+                }
+                values[count] = Self::parse_char(c)?;
+                count += 1;
+            }
+            if count != 81 {
+                return Err(PuzzleParseError::TooFewCells(count));
+            }
         }
+
+        Ok(Self::new_from(values))
+    }
+
+    /// Parses a single whitespace-separated token into an optional value.
+    fn parse_digit(token: &str) -> Result<ValueOption, PuzzleParseError> {
+        if token == "." || token == "0" || token == "_" {
+            return Ok(None);
+        }
+        let value: u8 = token
+            .parse()
+            .map_err(|_| PuzzleParseError::InvalidToken(token.to_string()))?;
+        match value {
+            0 => Ok(None),
+            v => Value::try_from(v)
+                .map(Some)
+                .map_err(|_| PuzzleParseError::InvalidToken(token.to_string())),
+        }
+    }
+
+    /// Parses a single character into an optional value, supporting both the
+    /// digit and the ksudoku letter encoding.
+    fn parse_char(c: char) -> Result<ValueOption, PuzzleParseError> {
+        match c {
+            '.' | '0' | '_' => Ok(None),
+            '1'..='9' => Value::try_from(c as u8 - b'0')
+                .map(Some)
+                .map_err(|_| PuzzleParseError::InvalidToken(c.to_string())),
+            // Letters encode values relative to `a`, so `b` is `1`, `c` is `2`, ...
+            'a'..='z' => {
+                let value = c as u8 - b'a';
+                match value {
+                    0 => Ok(None),
+                    v => Value::try_from(v)
+                        .map(Some)
+                        .map_err(|_| PuzzleParseError::InvalidToken(c.to_string())),
+                }
+            }
+            _ => Err(PuzzleParseError::InvalidToken(c.to_string())),
+        }
+    }
+
+    /// Serializes this state into the compact 81-character line format,
+    /// emitting `0` for any cell that is not yet solved.
+    pub fn to_line(&self) -> String {
+        let mut line = String::with_capacity(81);
+        for index in Index::range() {
+            let cell = self.get_at_index(index);
+            match cell.as_bitset().as_single_value() {
+                Some(value) => line.push((b'0' + (*value).get()) as char),
+                None => line.push('0'),
+            }
+        }
+        line
     }
 
     #[inline]
@@ -188,6 +384,25 @@ impl GameState {
         }
     }
 
+    /// Restricts the candidates at the specified cell to the given values,
+    /// i.e. keeps only the intersection of the current candidates and `values`.
+    /// No changes will be propagated.
+    ///
+    /// ## Returns
+    /// `true` if the candidate set changed, `false` otherwise.
+    #[inline]
+    pub fn set_many_at_index(&self, index: Index, values: ValueBitSet) -> bool {
+        let cell = self.cell_at_index(index);
+        let gc = cell.get();
+        let restricted = gc.to_bitset().with_intersection(values);
+        if restricted != gc.to_bitset() {
+            cell.set(GameCell::from_values(restricted));
+            true
+        } else {
+            false
+        }
+    }
+
     #[inline]
     fn cell_at_index(&self, index: Index) -> &Cell<GameCell> {
         debug_assert!((*index as usize) < self.cells.len());
@@ -457,6 +672,68 @@ mod tests {
         assert_eq!(expected_state, test_state);
     }
 
+    #[test]
+    fn from_line_single_string() {
+        let line = "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+        let state = GameState::from_line(line).unwrap();
+
+        assert!(state.get_at_xy(0, 0).is_exactly(Value::FIVE));
+        assert!(state.get_at_xy(1, 0).is_exactly(Value::THREE));
+        assert!(!state.get_at_xy(2, 0).is_solved());
+        assert!(state.get_at_xy(4, 0).is_exactly(Value::SEVEN));
+    }
+
+    #[test]
+    fn from_line_whitespace_grid() {
+        let grid = "5 3 . . 7 . . . . \
+                    6 . . 1 9 5 . . . \
+                    . 9 8 . . . . 6 . \
+                    8 . . . 6 . . . 3 \
+                    4 . . 8 . 3 . . 1 \
+                    7 . . . 2 . . . 6 \
+                    . 6 . . . . 2 8 . \
+                    . . . 4 1 9 . . 5 \
+                    . . . . 8 . . 7 9";
+        let state = GameState::from_line(grid).unwrap();
+        assert!(state.get_at_xy(0, 0).is_exactly(Value::FIVE));
+        assert!(state.get_at_xy(4, 8).is_exactly(Value::EIGHT));
+    }
+
+    #[test]
+    fn round_trips_through_line() {
+        let state = game_state();
+        let line = state.to_line();
+        let parsed = GameState::from_line(&line).unwrap();
+        assert_eq!(parsed.to_line(), line);
+    }
+
+    #[test]
+    fn ksudoku_letters_decode() {
+        // `_` is blank, `b` is 1, `j` is 9.
+        let line = "_bcdefghij".to_string() + &"_".repeat(71);
+        let state = GameState::from_line(line).unwrap();
+        assert!(!state.get_at_index(Index::new(0)).is_solved());
+        assert!(state.get_at_index(Index::new(1)).is_exactly(Value::ONE));
+        assert!(state.get_at_index(Index::new(9)).is_exactly(Value::NINE));
+    }
+
+    #[test]
+    fn progress_metrics() {
+        let empty = GameState::new();
+        assert_eq!(empty.solved_count(), 0);
+        assert_eq!(empty.progress(), 0.0);
+        assert_eq!(empty.total_candidates(), 81 * 9);
+        assert_eq!(empty.candidate_grid().len(), 81);
+
+        let state = game_state();
+        assert!(state.solved_count() > 0);
+        assert!(state.progress() > 0.0 && state.progress() < 1.0);
+
+        let rate = empty.solution_rate();
+        assert_eq!(rate.solved_fraction, 0.0);
+        assert_eq!(rate.remaining_candidates, 81 * 9);
+    }
+
     #[test]
     fn iter_cells() {
         let state = game_state();
@@ -0,0 +1,110 @@
+//! Difficulty grading of a board from the techniques its solution requires.
+//!
+//! The grader runs the logical [`DefaultSolver`] over a board and inspects the
+//! resulting [`SolveReport`]: the hardest technique that actually fired — and
+//! whether the solver had to fall back to search — determines the grade, while
+//! the report's weighted [`score`](SolveReport::score) provides a numeric
+//! companion value.
+
+use crate::cell_group::CellGroups;
+use crate::default_solver::{DefaultSolver, StepKind};
+use crate::strategies::Difficulty;
+use crate::GameState;
+
+/// A coarse, human-facing difficulty rating for a board.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum GameDifficulty {
+    /// Solvable with naked singles alone.
+    Trivial,
+    /// Needs hidden singles.
+    Easy,
+    /// Needs naked or hidden subsets.
+    Medium,
+    /// Needs intersection removal, fish, wings, colouring, chains or a
+    /// fall-back to search.
+    Hard,
+    /// No solution exists, or the solver could not reach one.
+    Unsolvable,
+}
+
+impl GameDifficulty {
+    /// Maps the hardest [`Difficulty`] a solve required onto a coarse grade.
+    fn from_difficulty(difficulty: Difficulty) -> Self {
+        match difficulty {
+            Difficulty::Trivial => GameDifficulty::Trivial,
+            Difficulty::Easy => GameDifficulty::Easy,
+            Difficulty::Medium => GameDifficulty::Medium,
+            Difficulty::Hard | Difficulty::Fiendish => GameDifficulty::Hard,
+        }
+    }
+}
+
+/// A graded board: its coarse [`GameDifficulty`] and the solver's weighted
+/// numeric score.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct GameGrade {
+    /// The coarse difficulty rating.
+    pub difficulty: GameDifficulty,
+    /// The weighted sum of the techniques (and guesses) the solve required.
+    pub score: usize,
+}
+
+/// Grades boards by the provenance of the deductions needed to solve them.
+pub struct Grader;
+
+impl Grader {
+    /// Grades `state` against `groups` by solving it and weighting the hardest
+    /// technique that was required; a board that needed a guess grades no lower
+    /// than [`GameDifficulty::Hard`].
+    pub fn grade<G: AsRef<CellGroups>>(state: &GameState, groups: G) -> GameGrade {
+        let report = DefaultSolver::new(groups.as_ref()).solve_report(state);
+
+        if !report.is_solved {
+            return GameGrade {
+                difficulty: GameDifficulty::Unsolvable,
+                score: report.score(),
+            };
+        }
+
+        // A board that fell back to search is hard regardless of which logical
+        // techniques also fired.
+        let probed = report
+            .steps
+            .iter()
+            .any(|step| step.kind == StepKind::Probe);
+
+        let difficulty = if probed {
+            GameDifficulty::Hard
+        } else {
+            report
+                .difficulty
+                .map_or(GameDifficulty::Trivial, GameDifficulty::from_difficulty)
+        };
+
+        GameGrade {
+            difficulty,
+            score: report.score(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grades_example_sudoku() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let grade = Grader::grade(&game.initial_state, &game.groups);
+        assert_ne!(grade.difficulty, GameDifficulty::Unsolvable);
+        assert!(grade.score > 0);
+    }
+
+    #[test]
+    fn empty_board_is_hard() {
+        let game = crate::example_games::sudoku::example_sudoku();
+        let grade = Grader::grade(&GameState::new(), &game.groups);
+        // An empty board is solved only by guessing.
+        assert_eq!(grade.difficulty, GameDifficulty::Hard);
+    }
+}
@@ -9,7 +9,7 @@ pub struct Index(u8);
 impl Index {
     #[inline]
     pub const fn new(index: u8) -> Self {
-        debug_assert!(index < 81);
+        debug_assert!((index as usize) < IndexBitSet::CAPACITY);
         Self(index)
     }
 
@@ -56,99 +56,121 @@ impl Debug for Index {
     }
 }
 
-/// A simple bitset for storing regular Sudoku-sized (i.e., up to 81) index values.
+/// A dense, word-array bitset for storing Sudoku index values.
 ///
 /// ## Technical Notes
-/// Practically this implementation allows for storing up to 127 different indexes.
+/// The backing store is a small fixed array of 64-bit words, following the
+/// dense word-array design used by `rustc`'s `BitVector`: bit `i` lives in
+/// word `i / 64` at position `i % 64`, and set operations fold over the words.
+/// Four words ([`CAPACITY`](Self::CAPACITY) bits) are kept inline (no heap allocation),
+/// which is enough for a classic 9×9 board (81 cells) as well as larger
+/// variants such as 16×16 (256 cells). The common 81-cell case still fits in
+/// the first two words. Widening [`Index`]/[`Coordinate`] past `u8`/9-wide to
+/// address multi-grid layouts remains future work.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct IndexBitSet {
-    /// We anticipate at most 81 fields on a standard Sudoku game.
-    /// We use a 128-bit type here to directly encode the field values,
-    /// even though this wastes 47 bits.
-    state: u128,
+    words: [u64; Self::WORDS],
 }
 
 impl IndexBitSet {
-    /// The mask for storing the actual values.
-    const MASK: u128 = 0b111111111_111111111_111111111_111111111_111111111_111111111_111111111_111111111_111111111u128;
+    /// The number of bits stored per backing word.
+    const WORD_BITS: usize = 64;
 
-    /// The set that contains all indexes.
-    pub const ALL: IndexBitSet = IndexBitSet { state: Self::MASK };
+    /// The number of inline words, sized to cover up to 256 indexes.
+    const WORDS: usize = 4;
+
+    /// The number of indexes this set can hold.
+    pub const CAPACITY: usize = Self::WORD_BITS * Self::WORDS;
+
+    /// The set that contains all indexes of a classic 9×9 board (0..=80).
+    pub const ALL: IndexBitSet = IndexBitSet {
+        words: [u64::MAX, (1u64 << 17) - 1, 0, 0],
+    };
 
     /// The set that contains no indexes.
-    pub const NONE: IndexBitSet = IndexBitSet { state: 0 };
+    pub const NONE: IndexBitSet = IndexBitSet {
+        words: [0; Self::WORDS],
+    };
 
     #[inline]
     pub const fn empty() -> Self {
-        Self { state: 0 }
+        Self {
+            words: [0; Self::WORDS],
+        }
     }
 
     #[inline]
     pub const fn with_index(mut self, index: Index) -> Self {
-        debug_assert!(index.0 < 81);
-        let value = index.0 as u128;
-        self.state |= (1u128 << value) & Self::MASK;
+        let value = index.0 as usize;
+        self.words[value / Self::WORD_BITS] |= 1u64 << (value % Self::WORD_BITS);
         self
     }
 
     #[inline]
     pub fn insert(&mut self, index: Index) -> &mut Self {
-        debug_assert!(index.0 < 81);
-        let value = index.0 as u128;
-        self.state |= (1u128 << value) & Self::MASK;
+        let value = index.0 as usize;
+        self.words[value / Self::WORD_BITS] |= 1u64 << (value % Self::WORD_BITS);
         self
     }
 
     #[inline]
     pub fn try_insert(&mut self, index: Index) -> bool {
-        debug_assert!(index.0 < 81);
-        let value = index.0 as u128;
-        let bitmask = (1u128 << value) & Self::MASK;
-        let contains = (self.state & bitmask) > 0;
-        self.state |= bitmask;
+        let value = index.0 as usize;
+        let word = &mut self.words[value / Self::WORD_BITS];
+        let bitmask = 1u64 << (value % Self::WORD_BITS);
+        let contains = (*word & bitmask) != 0;
+        *word |= bitmask;
         !contains
     }
 
     #[inline]
     pub const fn without_index(mut self, index: Index) -> Self {
-        debug_assert!(index.0 < 81);
-        let value = index.0 as u128;
-        self.state &= (!(1u128 << value)) & Self::MASK;
+        let value = index.0 as usize;
+        self.words[value / Self::WORD_BITS] &= !(1u64 << (value % Self::WORD_BITS));
         self
     }
 
     #[inline]
     pub fn remove(&mut self, index: Index) -> &mut Self {
-        debug_assert!(index.0 < 81);
-        let value = index.0 as u128;
-        self.state &= (!(1u128 << value)) & Self::MASK;
+        let value = index.0 as usize;
+        self.words[value / Self::WORD_BITS] &= !(1u64 << (value % Self::WORD_BITS));
         self
     }
 
     #[inline]
     pub const fn with_union(mut self, other: &IndexBitSet) -> Self {
-        self.state |= other.state & Self::MASK;
+        let mut w = 0;
+        while w < Self::WORDS {
+            self.words[w] |= other.words[w];
+            w += 1;
+        }
         self
     }
 
     #[inline]
     pub fn union(&mut self, other: &IndexBitSet) -> &mut Self {
-        self.state |= other.state & Self::MASK;
+        for w in 0..Self::WORDS {
+            self.words[w] |= other.words[w];
+        }
         self
     }
 
     #[inline]
     pub const fn overlaps_with(&self, other: &IndexBitSet) -> bool {
-        let state = (self.state & other.state) & Self::MASK;
-        state > 0
+        let mut w = 0;
+        while w < Self::WORDS {
+            if self.words[w] & other.words[w] != 0 {
+                return true;
+            }
+            w += 1;
+        }
+        false
     }
 
     #[inline]
     pub const fn contains(&self, index: Index) -> bool {
-        debug_assert!(index.0 < 81);
-        let value = index.0 as u128;
-        let flag = self.state & (1 << value);
-        flag != 0
+        let value = index.0 as usize;
+        self.words[value / Self::WORD_BITS] & (1u64 << (value % Self::WORD_BITS)) != 0
     }
 
     #[inline]
@@ -164,41 +186,50 @@ impl IndexBitSet {
 
     #[inline]
     pub const fn len(&self) -> usize {
-        (self.state & Self::MASK).count_ones() as _
+        let mut w = 0;
+        let mut count = 0u32;
+        while w < Self::WORDS {
+            count += self.words[w].count_ones();
+            w += 1;
+        }
+        count as usize
     }
 
     #[inline]
     pub const fn is_empty(&self) -> bool {
-        self.state & Self::MASK == 0
+        let mut w = 0;
+        while w < Self::WORDS {
+            if self.words[w] != 0 {
+                return false;
+            }
+            w += 1;
+        }
+        true
     }
 
     #[inline]
     pub const fn iter(&self) -> IndexBitSetIter {
-        IndexBitSetIter {
-            value: *self,
-            index: 0,
-        }
+        IndexBitSetIter { words: self.words }
     }
 }
 
 impl FromIterator<u8> for IndexBitSet {
     fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
-        Self {
-            state: iter.into_iter().fold(0u128, |state, value| {
-                debug_assert!(value < 81, "Index is out of range");
-                state | 1u128 << value
-            }),
+        let mut set = IndexBitSet::empty();
+        for value in iter {
+            set.insert(Index::new(value));
         }
+        set
     }
 }
 
 impl FromIterator<Index> for IndexBitSet {
     fn from_iter<T: IntoIterator<Item = Index>>(iter: T) -> Self {
-        Self {
-            state: iter
-                .into_iter()
-                .fold(0u128, |state, value| state | 1u128 << value.0),
+        let mut set = IndexBitSet::empty();
+        for value in iter {
+            set.insert(value);
         }
+        set
     }
 }
 
@@ -212,31 +243,61 @@ impl IntoIterator for IndexBitSet {
     }
 }
 
+/// Convenience alias for the iterator produced by
+/// [`CellGroup::iter_indexes`](crate::cell_group::CellGroup::iter_indexes).
+pub type IntoIndexBitSetIter = IndexBitSetIter;
+
+/// Iterates the set indexes of an [`IndexBitSet`] in ascending order by
+/// scanning the backing words one set bit at a time.
 pub struct IndexBitSetIter {
-    value: IndexBitSet,
-    index: u8,
+    words: [u64; IndexBitSet::WORDS],
 }
 
 impl Iterator for IndexBitSetIter {
     type Item = Index;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let state = self.value.state;
-        let mut index = self.index;
-        while index < 81 {
-            let test = 1u128 << index;
-            if state & test != 0 {
-                self.index = index + 1;
-                return Some(Index::new(index));
+        for w in 0..IndexBitSet::WORDS {
+            let word = self.words[w];
+            if word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                // Clear the lowest set bit.
+                self.words[w] &= word - 1;
+                return Some(Index::new((w * IndexBitSet::WORD_BITS + bit) as u8));
             }
-            index += 1;
         }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
 
-        self.index = 81;
+impl DoubleEndedIterator for IndexBitSetIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        for w in (0..IndexBitSet::WORDS).rev() {
+            let word = self.words[w];
+            if word != 0 {
+                let bit = (IndexBitSet::WORD_BITS - 1) - word.leading_zeros() as usize;
+                // Clear the highest set bit.
+                self.words[w] &= !(1u64 << bit);
+                return Some(Index::new((w * IndexBitSet::WORD_BITS + bit) as u8));
+            }
+        }
         None
     }
 }
 
+impl ExactSizeIterator for IndexBitSetIter {
+    #[inline]
+    fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+}
+
 pub trait CollectIndexBitSet {
     fn collect_bitset(self) -> IndexBitSet;
 }
@@ -355,4 +416,24 @@ mod tests {
         assert_eq!(iter.next(), None);
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn iter_double_ended() {
+        let a = Index::new(80);
+        let b = Index::new(17);
+        let c = Index::new(2);
+
+        let bitset = IndexBitSet::default()
+            .with_index(a)
+            .with_index(b)
+            .with_index(c);
+        let mut iter = bitset.iter();
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(c));
+        assert_eq!(iter.next_back(), Some(a));
+        assert_eq!(iter.next(), Some(b));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
 }
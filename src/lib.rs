@@ -1,26 +1,50 @@
+mod candidate_ranking;
 mod cell_group;
+mod codec;
 mod coordinate;
 mod default_solver;
 pub mod example_games;
 mod game;
+pub mod generator;
 mod game_cell;
 mod game_state;
+mod grading;
 mod index;
+#[cfg(feature = "serde")]
+mod serialization;
+mod state_stack;
+mod strategies;
 mod value;
 pub mod visualization;
 
 pub mod prelude {
-    pub use crate::cell_group::{CellGroup, CellGroups, OverlappingGroups};
+    pub use crate::candidate_ranking::{CandidateRanking, RankedAssignment};
+    pub use crate::cell_group::{CellGroup, CellGroups, OverlappingGroups, PartitionError};
+    pub use crate::codec::PuzzleCodecError;
     pub use crate::coordinate::Coordinate;
-    pub use crate::default_solver::{DefaultSolver, Unsolvable};
+    pub use crate::default_solver::{
+        DefaultSolver, DefaultSolverConfig, SolveReport, Solutions, SolverStep, StepKind,
+        TechniqueCount, Unsolvable,
+    };
+    pub use crate::strategies::Difficulty;
     pub use crate::game::Game;
+    pub use crate::generator::{GeneratedPuzzle, Generator, PuzzleGenerator, TargetDifficulty};
     pub use crate::game_cell::{GameCell, IndexedGameCell};
     pub use crate::game_state::GameState;
+    pub use crate::game_state::PuzzleParseError;
+    pub use crate::game_state::SolutionRate;
+    pub use crate::grading::{GameDifficulty, GameGrade, Grader};
     pub use crate::index::Index;
     pub use crate::index::IndexBitSet;
     pub use crate::value::Value;
     pub use crate::value::ValueBitSet;
+    pub use crate::value::DEFAULT_SYMBOL_COUNT;
     pub use crate::value::ValueOption;
+
+    #[cfg(feature = "serde")]
+    pub use crate::serialization::{
+        from_json, to_json, CellGroupDto, CellGroupsDto, GameStateDto, SolverStep as SolverStepDto,
+    };
 }
 
 pub fn add(left: usize, right: usize) -> usize {
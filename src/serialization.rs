@@ -0,0 +1,128 @@
+//! Serializable representations of games, groups and solver traces.
+//!
+//! The in-memory types ([`GameState`], [`CellGroups`]) use interior mutability
+//! and packed bitsets that do not map cleanly onto a stable wire format, so
+//! this module provides plain data-transfer objects that derive serde's
+//! [`Serialize`]/[`Deserialize`] and convert to and from the runtime types.
+//! This lets games, irregular group layouts and step-by-step solver traces be
+//! saved to JSON, diffed and consumed by other tools.
+
+use crate::cell_group::{CellGroup, CellGroupType, CellGroups};
+use crate::index::Index;
+use crate::value::ValueBitSet;
+use crate::GameState;
+use serde::{Deserialize, Serialize};
+
+/// A serializable snapshot of a [`GameState`], storing the remaining candidate
+/// values of every cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameStateDto {
+    /// The candidate values (1-9) of each cell, in index order.
+    pub cells: Vec<Vec<u8>>,
+}
+
+impl From<&GameState> for GameStateDto {
+    fn from(state: &GameState) -> Self {
+        let cells = (0..state.len())
+            .map(|i| {
+                state
+                    .get_at_index(Index::new(i as u8))
+                    .iter_candidates()
+                    .map(|v| (*v).get())
+                    .collect()
+            })
+            .collect();
+        Self { cells }
+    }
+}
+
+impl From<&GameStateDto> for GameState {
+    fn from(dto: &GameStateDto) -> Self {
+        let state = GameState::new_sized(dto.cells.len());
+        for (i, candidates) in dto.cells.iter().enumerate() {
+            if candidates.is_empty() {
+                continue;
+            }
+            let bitset = ValueBitSet::from(candidates.as_slice());
+            state.set_many_at_index(Index::new(i as u8), bitset);
+        }
+        state
+    }
+}
+
+/// A serializable cell group, retaining its type and index membership so that
+/// non-standard overlapping groups round-trip faithfully.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellGroupDto {
+    pub id: Option<usize>,
+    pub group_type: u8,
+    pub indexes: Vec<u8>,
+}
+
+/// A serializable collection of [`CellGroups`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellGroupsDto {
+    pub groups: Vec<CellGroupDto>,
+}
+
+impl From<&CellGroups> for CellGroupsDto {
+    fn from(groups: &CellGroups) -> Self {
+        let groups = groups
+            .iter()
+            .map(|group| CellGroupDto {
+                id: group.id,
+                group_type: group.group_type as u8,
+                indexes: group.iter_indexes().map(|i| *i).collect(),
+            })
+            .collect();
+        Self { groups }
+    }
+}
+
+impl From<&CellGroupsDto> for CellGroups {
+    fn from(dto: &CellGroupsDto) -> Self {
+        let mut groups = CellGroups::default();
+        for group in &dto.groups {
+            let mut cell_group =
+                CellGroup::new(group.id.unwrap_or(0), group_type_from_u8(group.group_type));
+            for &index in &group.indexes {
+                cell_group.add_index(Index::new(index));
+            }
+            groups.add_group(cell_group);
+        }
+        groups
+    }
+}
+
+/// A single step emitted while the solver runs: which strategy fired, the
+/// affected cells, and the values placed or eliminated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolverStep {
+    /// The name of the [`Strategy`](crate::strategies::Strategy) that fired.
+    pub strategy: String,
+    /// The indexes whose candidates changed.
+    pub indexes: Vec<u8>,
+    /// The values that were set on the affected cells, if any.
+    pub set: Vec<u8>,
+    /// The values that were eliminated from the affected cells, if any.
+    pub forgotten: Vec<u8>,
+}
+
+/// Serializes any serde-serializable value to a pretty JSON string.
+pub fn to_json<T: Serialize>(value: &T) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(value)
+}
+
+/// Deserializes a value from a JSON string.
+pub fn from_json<'a, T: Deserialize<'a>>(json: &'a str) -> Result<T, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+fn group_type_from_u8(value: u8) -> CellGroupType {
+    match value {
+        1 => CellGroupType::StandardBlock,
+        2 => CellGroupType::StandardRow,
+        3 => CellGroupType::StandardColumn,
+        _ => CellGroupType::Custom,
+    }
+}
@@ -1,13 +1,23 @@
 use crate::GameState;
 use log::trace;
+use std::collections::HashSet;
 use std::fmt::{Debug, Display, Formatter};
 
 /// A state stack used for branching solvers that assigns IDs
 /// to forked [`GameState`] instances.
+///
+/// The stack doubles as a transposition table: every pushed state is hashed
+/// by its candidate bitsets and remembered, so convergent or symmetric search
+/// paths that reach an already-seen board are rejected instead of re-explored.
 pub struct StateStack {
     stack: Vec<StateStackEntry>,
     max_depth: usize,
     forks: usize,
+    visited: HashSet<u64>,
+    pruned: usize,
+    /// Whether the transposition table is active. When `false` every pushed
+    /// state is accepted, which lets callers measure the raw branch count.
+    dedup: bool,
 }
 
 pub struct StateStackEntry {
@@ -17,8 +27,24 @@ pub struct StateStackEntry {
 
 pub struct BranchId(usize);
 
+impl BranchId {
+    /// The numeric identifier of this branch (`0` for the root).
+    #[inline]
+    pub const fn id(&self) -> usize {
+        self.0
+    }
+}
+
 impl StateStack {
     pub fn new_with(state: GameState) -> Self {
+        Self::new_with_dedup(state, true)
+    }
+
+    /// Creates a stack with the transposition table explicitly enabled or
+    /// disabled. With `dedup` set to `false` no branch is ever pruned.
+    pub fn new_with_dedup(state: GameState, dedup: bool) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(state.fingerprint());
         Self {
             stack: vec![StateStackEntry {
                 branch_id: BranchId(0),
@@ -26,10 +52,22 @@ impl StateStack {
             }],
             max_depth: 1,
             forks: 0,
+            visited,
+            pruned: 0,
+            dedup,
         }
     }
 
-    pub fn push(&mut self, state: GameState) {
+    /// Pushes a new branch state, unless an identical board has already been
+    /// enqueued. Returns `true` if the state was accepted and `false` if it was
+    /// pruned as a duplicate.
+    pub fn push(&mut self, state: GameState) -> bool {
+        if self.dedup && !self.visited.insert(state.fingerprint()) {
+            self.pruned += 1;
+            trace!("Pruned already-visited branch state");
+            return false;
+        }
+
         self.forks += 1;
         self.stack.push(StateStackEntry {
             branch_id: BranchId(self.forks),
@@ -37,6 +75,7 @@ impl StateStack {
         });
         self.max_depth = self.max_depth.max(self.len());
         trace!("Enqueued state as id {id}", id = self.forks);
+        true
     }
 
     pub fn pop(&mut self) -> Option<StateStackEntry> {
@@ -60,6 +99,11 @@ impl StateStack {
     pub fn num_forks(&self) -> usize {
         self.forks
     }
+
+    /// The number of branch states rejected as duplicates.
+    pub fn num_pruned(&self) -> usize {
+        self.pruned
+    }
 }
 
 impl Debug for BranchId {
@@ -0,0 +1,155 @@
+use crate::cell_group::{CellGroupType, CellGroups};
+use crate::game_state::{GameState, InvalidGameState};
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
+use crate::Value;
+use log::debug;
+use std::fmt::{Debug, Formatter};
+
+/// Identifies and realizes the two box/line locked-candidate patterns.
+///
+/// *Pointing*: when every candidate for a value inside a block shares a single
+/// row or column, the value is removed from the rest of that line outside the
+/// block. *Claiming*: when every candidate for a value inside a row or column
+/// falls within a single block, the value is removed from the rest of that
+/// block.
+pub struct BoxLine {
+    enabled: bool,
+}
+
+impl BoxLine {
+    pub fn new_box(enabled: bool) -> Box<Self> {
+        Box::new(Self { enabled })
+    }
+}
+
+impl Debug for BoxLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Box/line reduction")
+    }
+}
+
+impl Strategy for BoxLine {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Hard
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+
+        // Pointing: candidates locked to one line inside a block.
+        for block in groups
+            .iter()
+            .filter(|g| g.group_type == CellGroupType::StandardBlock)
+        {
+            for value in Value::range() {
+                let cells: Vec<_> = block
+                    .iter_indexes()
+                    .filter(|&i| {
+                        let cell = state.get_at_index(i);
+                        !cell.is_solved() && cell.contains(value)
+                    })
+                    .collect();
+
+                if cells.len() < 2 {
+                    continue;
+                }
+
+                let first = cells[0].into_coordinate();
+                let line_type = if cells.iter().all(|i| i.into_coordinate().y == first.y) {
+                    CellGroupType::StandardRow
+                } else if cells.iter().all(|i| i.into_coordinate().x == first.x) {
+                    CellGroupType::StandardColumn
+                } else {
+                    continue;
+                };
+
+                for index in groups
+                    .get_peer_indexes(cells[0], line_type)
+                    .filter(|&i| !block.contains(i))
+                {
+                    if state.forget_at_index(index, value) {
+                        applied_some = true;
+                        debug!(
+                            "Pointing removed value {value:?} along {line_type:?}",
+                            value = value,
+                            line_type = line_type
+                        );
+                    }
+                }
+            }
+        }
+
+        // Claiming: candidates locked to one block inside a line.
+        for line in groups.iter().filter(|g| {
+            g.group_type == CellGroupType::StandardRow
+                || g.group_type == CellGroupType::StandardColumn
+        }) {
+            for value in Value::range() {
+                let cells: Vec<_> = line
+                    .iter_indexes()
+                    .filter(|&i| {
+                        let cell = state.get_at_index(i);
+                        !cell.is_solved() && cell.contains(value)
+                    })
+                    .collect();
+
+                if cells.len() < 2 {
+                    continue;
+                }
+
+                let block = match groups.get_groups_at_index(cells[0]) {
+                    Ok(groups) => groups
+                        .into_iter()
+                        .find(|g| g.group_type == CellGroupType::StandardBlock),
+                    Err(_) => None,
+                };
+                let block = match block {
+                    Some(block) => block,
+                    None => continue,
+                };
+
+                if !cells.iter().all(|&i| block.contains(i)) {
+                    continue;
+                }
+
+                for index in block.iter_indexes().filter(|&i| !line.contains(i)) {
+                    if state.forget_at_index(index, value) {
+                        applied_some = true;
+                        debug!(
+                            "Claiming removed value {value:?} in {group_type:?}",
+                            value = value,
+                            group_type = line.group_type
+                        );
+                    }
+                }
+            }
+        }
+
+        if applied_some {
+            Ok(StrategyResult::AppliedChange)
+        } else {
+            Ok(StrategyResult::NoChange)
+        }
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy is not group aware")
+    }
+}
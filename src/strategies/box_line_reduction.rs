@@ -0,0 +1,257 @@
+use crate::cell_group::{CellGroupType, CellGroups};
+use crate::game_state::{GameState, InvalidGameState};
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
+use crate::Value;
+use log::debug;
+use std::fmt::{Debug, Formatter};
+
+/// Identifies and realizes the Box/Line Reduction strategy (the dual of
+/// [`Pointing`](crate::strategies::Pointing)).
+///
+/// ## Example
+/// When every candidate for a value inside a row (or column) lies within a
+/// single block, that value must be placed on that line within the block.
+/// It can therefore be removed from the remaining cells of the block that
+/// lie outside the row (or column).
+pub struct LineBoxReduction {
+    enabled: bool,
+}
+
+impl LineBoxReduction {
+    pub fn new_box(enabled: bool) -> Box<Self> {
+        Box::new(Self { enabled })
+    }
+}
+
+impl Debug for LineBoxReduction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Box/Line reduction")
+    }
+}
+
+impl Strategy for LineBoxReduction {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Hard
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+
+        for line in groups.iter().filter(|g| {
+            g.group_type == CellGroupType::StandardRow
+                || g.group_type == CellGroupType::StandardColumn
+        }) {
+            for value in Value::range() {
+                // Collect the unsolved cells on this line that still carry the value.
+                let cells: Vec<_> = line
+                    .iter_indexes()
+                    .filter(|&i| {
+                        let cell = state.get_at_index(i);
+                        !cell.is_solved() && cell.contains(value)
+                    })
+                    .collect();
+
+                if cells.len() < 2 {
+                    continue;
+                }
+
+                // Locate the block of the first candidate cell.
+                let block = match groups.get_groups_at_index(cells[0]) {
+                    Ok(groups) => groups
+                        .into_iter()
+                        .find(|g| g.group_type == CellGroupType::StandardBlock),
+                    Err(_) => None,
+                };
+                let block = match block {
+                    Some(block) => block,
+                    None => continue,
+                };
+
+                // The value is only confined if all candidates share that block.
+                if !cells.iter().all(|&i| block.contains(i)) {
+                    continue;
+                }
+
+                // Eliminate the value from the rest of the block, outside the line.
+                for index in block.iter_indexes().filter(|&i| !line.contains(i)) {
+                    applied_some |= state.forget_at_index(index, value);
+                }
+
+                if applied_some {
+                    debug!(
+                        "Applied Box/Line reduction for value {value:?} in {group_type:?}",
+                        value = value,
+                        group_type = line.group_type
+                    );
+                }
+            }
+        }
+
+        if applied_some {
+            Ok(StrategyResult::AppliedChange)
+        } else {
+            Ok(StrategyResult::NoChange)
+        }
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy is not group aware")
+    }
+}
+
+/// Realizes box/line reduction in both directions through [`apply_in_group`],
+/// combining the pointing (block → line) and claiming (line → block) passes
+/// into a single group-aware strategy.
+///
+/// Where [`Pointing`](crate::strategies::Pointing) and [`LineBoxReduction`]
+/// each scan one kind of group, this variant leans on the trait's default
+/// [`apply`] loop: the block pass eliminates a value from the line it is
+/// confined to, and the row/column passes eliminate it from the block it is
+/// confined to. Both are expressed as set differences over
+/// [`CellGroups::get_peer_indexes`].
+///
+/// [`apply_in_group`]: Strategy::apply_in_group
+/// [`apply`]: Strategy::apply
+pub struct BoxLineReduction {
+    enabled: bool,
+}
+
+impl BoxLineReduction {
+    pub fn new_box(enabled: bool) -> Box<Self> {
+        Box::new(Self { enabled })
+    }
+}
+
+impl Debug for BoxLineReduction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Box/Line reduction")
+    }
+}
+
+impl Strategy for BoxLineReduction {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Hard
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply_in_group(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+        group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+
+        match group_type {
+            // Pointing: candidates confined to one line of a block are removed
+            // from the rest of that line.
+            CellGroupType::StandardBlock => {
+                for block in groups
+                    .iter()
+                    .filter(|g| g.group_type == CellGroupType::StandardBlock)
+                {
+                    for value in Value::range() {
+                        let cells: Vec<_> = block
+                            .iter_indexes()
+                            .filter(|&i| {
+                                let cell = state.get_at_index(i);
+                                !cell.is_solved() && cell.contains(value)
+                            })
+                            .collect();
+
+                        if cells.len() < 2 {
+                            continue;
+                        }
+
+                        let first = cells[0].into_coordinate();
+                        let line_type = if cells.iter().all(|i| i.into_coordinate().y == first.y) {
+                            CellGroupType::StandardRow
+                        } else if cells.iter().all(|i| i.into_coordinate().x == first.x) {
+                            CellGroupType::StandardColumn
+                        } else {
+                            continue;
+                        };
+
+                        // The line outside the block is the difference of the
+                        // line's peer indexes and the block's indexes.
+                        for index in groups
+                            .get_peer_indexes(cells[0], line_type)
+                            .filter(|&i| !block.contains(i))
+                        {
+                            applied_some |= state.forget_at_index(index, value);
+                        }
+                    }
+                }
+            }
+            // Claiming: candidates confined to one block of a line are removed
+            // from the rest of that block.
+            CellGroupType::StandardRow | CellGroupType::StandardColumn => {
+                for line in groups.iter().filter(|g| g.group_type == group_type) {
+                    for value in Value::range() {
+                        let cells: Vec<_> = line
+                            .iter_indexes()
+                            .filter(|&i| {
+                                let cell = state.get_at_index(i);
+                                !cell.is_solved() && cell.contains(value)
+                            })
+                            .collect();
+
+                        if cells.len() < 2 {
+                            continue;
+                        }
+
+                        // The block outside the line is the difference of the
+                        // block's peer indexes and the line's indexes.
+                        let confined = cells.iter().all(|&i| {
+                            groups
+                                .get_peer_indexes(cells[0], CellGroupType::StandardBlock)
+                                .any(|b| b == i)
+                        });
+                        if !confined {
+                            continue;
+                        }
+
+                        for index in groups
+                            .get_peer_indexes(cells[0], CellGroupType::StandardBlock)
+                            .filter(|&i| !line.contains(i))
+                        {
+                            applied_some |= state.forget_at_index(index, value);
+                        }
+                    }
+                }
+            }
+            CellGroupType::Custom => {}
+        }
+
+        if applied_some {
+            debug!("Applied Box/Line reduction in {group_type:?}", group_type = group_type);
+            Ok(StrategyResult::AppliedChange)
+        } else {
+            Ok(StrategyResult::NoChange)
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::cell_group::{CellGroupType, CellGroups};
+use crate::game_state::{GameState, InvalidGameState};
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
+use crate::{Coordinate, Value};
+use log::debug;
+use std::fmt::{Debug, Formatter};
+
+/// Identifies and realizes the "fish" family of strategies, generalizing the
+/// X-Wing (order 2) to Swordfish (order 3) and Jellyfish (order 4).
+///
+/// A fish of order `N` for a value consists of `N` base lines (rows or
+/// columns) whose candidate positions for that value span exactly `N` cover
+/// lines of the opposite orientation. Because each base line must place the
+/// value in one of the cover lines, the value can be eliminated from every
+/// cover-line cell that lies outside the base lines.
+pub struct Fish {
+    order: usize,
+    enabled: bool,
+}
+
+impl Fish {
+    pub fn new_box(order: usize, enabled: bool) -> Box<Self> {
+        Box::new(Self { order, enabled })
+    }
+
+    fn label(&self) -> &'static str {
+        match self.order {
+            2 => "X-Wing",
+            3 => "Swordfish",
+            4 => "Jellyfish",
+            _ => "Fish",
+        }
+    }
+}
+
+impl Debug for Fish {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+impl Strategy for Fish {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Fiendish
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        _groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+
+        for value in Value::range() {
+            // Rows as base lines, columns as cover lines, and the transpose.
+            applied_some |= self.search(state, value, Orientation::Row);
+            applied_some |= self.search(state, value, Orientation::Column);
+        }
+
+        if applied_some {
+            Ok(StrategyResult::AppliedChange)
+        } else {
+            Ok(StrategyResult::NoChange)
+        }
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy is not group aware")
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Orientation {
+    Row,
+    Column,
+}
+
+impl Fish {
+    fn search(&self, state: &GameState, value: Value, orientation: Orientation) -> bool {
+        // For each base line, collect the cover-line positions the value can occupy.
+        let mut base_lines: Vec<(u8, Vec<u8>)> = Vec::new();
+        for base in 0..9u8 {
+            let mut covers = Vec::new();
+            for cover in 0..9u8 {
+                let (x, y) = match orientation {
+                    Orientation::Row => (cover, base),
+                    Orientation::Column => (base, cover),
+                };
+                let cell = state.get_at_coord(Coordinate::new(x, y));
+                if !cell.is_solved() && cell.contains(value) {
+                    covers.push(cover);
+                }
+            }
+            // A base line contributes between two and `order` candidates.
+            if covers.len() >= 2 && covers.len() <= self.order {
+                base_lines.push((base, covers));
+            }
+        }
+
+        if base_lines.len() < self.order {
+            return false;
+        }
+
+        let mut applied_some = false;
+        for combo in combinations(&base_lines, self.order) {
+            // The union of cover positions across the chosen base lines.
+            let mut cover_set: Vec<u8> = Vec::new();
+            for &(_, ref covers) in &combo {
+                for &c in covers {
+                    if !cover_set.contains(&c) {
+                        cover_set.push(c);
+                    }
+                }
+            }
+
+            if cover_set.len() != self.order {
+                continue;
+            }
+
+            let base_indices: Vec<u8> = combo.iter().map(|&(base, _)| base).collect();
+
+            // Eliminate the value from the cover lines in every non-base line.
+            for &cover in &cover_set {
+                for base in 0..9u8 {
+                    if base_indices.contains(&base) {
+                        continue;
+                    }
+                    let (x, y) = match orientation {
+                        Orientation::Row => (cover, base),
+                        Orientation::Column => (base, cover),
+                    };
+                    let index = Coordinate::new(x, y).into_index();
+                    if state.forget_at_index(index, value) {
+                        applied_some = true;
+                    }
+                }
+            }
+
+            if applied_some {
+                debug!(
+                    "Applied {label} for value {value:?} over {order} lines",
+                    label = self.label(),
+                    value = value,
+                    order = self.order
+                );
+            }
+        }
+
+        applied_some
+    }
+}
+
+/// Produces all `size`-element combinations of the slice, preserving order.
+fn combinations<T: Clone>(items: &[T], size: usize) -> Vec<Vec<T>> {
+    let mut result = Vec::new();
+    let mut current = Vec::with_capacity(size);
+    fn recurse<T: Clone>(
+        items: &[T],
+        start: usize,
+        size: usize,
+        current: &mut Vec<T>,
+        result: &mut Vec<Vec<T>>,
+    ) {
+        if current.len() == size {
+            result.push(current.clone());
+            return;
+        }
+        for i in start..items.len() {
+            current.push(items[i].clone());
+            recurse(items, i + 1, size, current, result);
+            current.pop();
+        }
+    }
+    if size > 0 && size <= items.len() {
+        recurse(items, 0, size, &mut current, &mut result);
+    }
+    result
+}
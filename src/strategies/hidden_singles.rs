@@ -35,6 +35,10 @@ impl Strategy for HiddenSingles {
         self.enabled
     }
 
+    fn difficulty(&self) -> crate::strategies::Difficulty {
+        crate::strategies::Difficulty::Easy
+    }
+
     fn always_continue(&self) -> bool {
         false
     }
@@ -0,0 +1,159 @@
+use crate::cell_group::{CellGroupType, CellGroups};
+use crate::game_state::{GameState, InvalidGameState};
+use crate::index::Index;
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
+use crate::Value;
+use log::debug;
+use std::fmt::{Debug, Formatter};
+
+/// Identifies and realizes forcing chains by propagating binary implications.
+///
+/// For every bivalue cell the strategy assumes each of its two candidates in
+/// turn and follows the implications — a placement forces its peers to drop the
+/// value, which may create further naked singles — until the board settles or a
+/// cell runs out of candidates. Two conclusions can follow:
+///
+/// * If assuming a literal reaches a contradiction, that literal is false and
+///   the cell is placed on its other candidate.
+/// * If both opposite assumptions force the *same* placement somewhere else,
+///   that placement holds unconditionally and is made.
+///
+/// This yields the inference power of short forcing chains without resorting to
+/// the full branching search.
+pub struct ImplicationChains {
+    enabled: bool,
+}
+
+impl ImplicationChains {
+    pub fn new_box(enabled: bool) -> Box<Self> {
+        Box::new(Self { enabled })
+    }
+
+    /// Propagates the assumption `value` at `index` to a fixed point, returning
+    /// the settled board or `None` if the assumption leads to a contradiction.
+    fn settle(
+        base: &GameState,
+        index: Index,
+        value: Value,
+        groups: &CellGroups,
+    ) -> Option<GameState> {
+        let state = base.clone();
+        state.place_and_propagate_at_index(index, value, groups);
+
+        loop {
+            let mut changed = false;
+            for index in Index::range() {
+                let cell = state.get_at_index(index);
+                if cell.is_impossible() {
+                    return None;
+                }
+                if cell.is_solved() {
+                    let placed = cell.iter_candidates().next().expect("a solved cell");
+                    changed |= state.place_and_propagate_at_index(index, placed, groups);
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        Some(state)
+    }
+}
+
+impl Debug for ImplicationChains {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Implication chains")
+    }
+}
+
+impl Strategy for ImplicationChains {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Fiendish
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+
+        for index in Index::range() {
+            let cell = state.get_at_index(index);
+            if cell.is_solved() || cell.len() != 2 {
+                continue;
+            }
+
+            let mut candidates = cell.iter_candidates();
+            let first = candidates.next().expect("a bivalue cell");
+            let second = candidates.next().expect("a bivalue cell");
+
+            let assume_first = Self::settle(state, index, first, groups);
+            let assume_second = Self::settle(state, index, second, groups);
+
+            match (assume_first, assume_second) {
+                // Both assumptions fail: the board is already inconsistent.
+                (None, None) => return Err(InvalidGameState {}),
+                // One assumption is impossible, so the cell takes the other.
+                (None, Some(_)) => {
+                    state.place_and_propagate_at_index(index, second, groups);
+                    applied_some = true;
+                    debug!("Implication chains eliminated {first:?} at {index:?}", first = first, index = index);
+                }
+                (Some(_), None) => {
+                    state.place_and_propagate_at_index(index, first, groups);
+                    applied_some = true;
+                    debug!("Implication chains eliminated {second:?} at {index:?}", second = second, index = index);
+                }
+                // Both hold: any cell both branches solve identically is forced.
+                (Some(a), Some(b)) => {
+                    for other in Index::range() {
+                        if state.get_at_index(other).is_solved() {
+                            continue;
+                        }
+                        let va = a.get_at_index(other);
+                        let vb = b.get_at_index(other);
+                        if let (Some(pa), Some(pb)) = (
+                            va.as_bitset().as_single_value(),
+                            vb.as_bitset().as_single_value(),
+                        ) {
+                            if pa == pb {
+                                state.place_and_propagate_at_index(other, pa, groups);
+                                applied_some = true;
+                                debug!(
+                                    "Implication chains forced {pa:?} at {other:?}",
+                                    pa = pa,
+                                    other = other
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(if applied_some {
+            StrategyResult::AppliedChange
+        } else {
+            StrategyResult::NoChange
+        })
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy is not group aware")
+    }
+}
@@ -1,17 +1,81 @@
+mod box_line;
+mod box_line_reduction;
+mod fish;
 mod hidden_singles;
+mod implication_chains;
+mod intersection_removal;
 mod naked_singles;
-mod naked_twins;
+mod pointing;
+mod simple_coloring;
+mod subset;
+mod wxyz_wing;
+mod xwing;
 
 use crate::cell_group::{CellGroupType, CellGroups};
 use crate::game_state::{GameState, InvalidGameState};
 use std::fmt::Debug;
 use std::ops::{BitOr, BitOrAssign};
 
+pub use box_line::BoxLine;
+pub use box_line_reduction::{BoxLineReduction, LineBoxReduction};
+pub use fish::Fish;
 pub use hidden_singles::HiddenSingles;
+pub use implication_chains::ImplicationChains;
+pub use intersection_removal::IntersectionRemoval;
 pub use naked_singles::NakedSingles;
-pub use naked_twins::NakedTwins;
+pub use pointing::Pointing;
+pub use simple_coloring::{Colouring, SimpleColoring};
+pub use subset::{HiddenSubset, NakedSubset};
+pub use wxyz_wing::WxyzWing;
+pub use xwing::XWing;
+
+/// The relative difficulty of a solving technique, used to grade puzzles.
+///
+/// The ordering follows the usual staged-logic levels: naked/hidden singles
+/// are the easiest placements, subsets (twins/triples) sit in the middle and
+/// fish such as the X-Wing are the hardest techniques currently implemented.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Difficulty {
+    /// Naked singles — a cell with a single remaining candidate.
+    Trivial = 1,
+    /// Hidden singles.
+    Easy = 2,
+    /// Naked and hidden subsets (twins, triples, ...).
+    Medium = 3,
+    /// Intersection removal (pointing / box-line reduction).
+    Hard = 4,
+    /// Fish (X-Wing, Swordfish, ...) and chaining techniques.
+    Fiendish = 5,
+}
 
 pub trait Strategy: Debug {
+    /// Indicates whether this strategy is enabled and should be executed.
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// The difficulty level this strategy contributes to a puzzle's grade.
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    /// The relative cost of this strategy.
+    ///
+    /// Defaults to the numeric [`Difficulty`] level so that cheaper placements
+    /// rank below expensive chaining techniques. The solver and the grader do
+    /// not call it: strategies run in the solver's fixed order and are weighted
+    /// by [`difficulty`](Self::difficulty).
+    fn rank(&self) -> usize {
+        self.difficulty() as usize
+    }
+
+    /// A stable, human-readable name for this strategy.
+    ///
+    /// Defaults to the [`Debug`] representation, which every strategy provides.
+    fn name(&self) -> String {
+        format!("{self:?}")
+    }
+
     /// Indicates whether the next strategy should always be executed
     /// (if `true`) regardless of the return value of [`Strategy::apply`]
     /// or (if `false`) whether execution should restart with the first registered
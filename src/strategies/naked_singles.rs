@@ -29,6 +29,10 @@ impl Debug for NakedSingles {
 }
 
 impl Strategy for NakedSingles {
+    fn difficulty(&self) -> crate::strategies::Difficulty {
+        crate::strategies::Difficulty::Trivial
+    }
+
     fn always_continue(&self) -> bool {
         true
     }
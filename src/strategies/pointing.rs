@@ -0,0 +1,116 @@
+use crate::cell_group::{CellGroupType, CellGroups};
+use crate::game_state::{GameState, InvalidGameState};
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
+use crate::Value;
+use log::debug;
+use std::fmt::{Debug, Formatter};
+
+/// Identifies and realizes the Pointing Pairs/Triples strategy.
+///
+/// ## Example
+/// When every candidate for a value inside a block lies on a single row
+/// (or column), that value must be placed somewhere on that line within
+/// the block. It can therefore be removed from the remaining cells of the
+/// row (or column) that lie outside the block.
+pub struct Pointing {
+    enabled: bool,
+}
+
+impl Pointing {
+    pub fn new_box(enabled: bool) -> Box<Self> {
+        Box::new(Self { enabled })
+    }
+}
+
+impl Debug for Pointing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Pointing")
+    }
+}
+
+impl Strategy for Pointing {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Hard
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+
+        for block in groups
+            .iter()
+            .filter(|g| g.group_type == CellGroupType::StandardBlock)
+        {
+            for value in Value::range() {
+                // Collect the unsolved cells in this block that still carry the value.
+                let cells: Vec<_> = block
+                    .iter_indexes()
+                    .filter(|&i| {
+                        let cell = state.get_at_index(i);
+                        !cell.is_solved() && cell.contains(value)
+                    })
+                    .collect();
+
+                // A pointing pair needs at least two cells; a single cell is a
+                // Hidden Single and handled elsewhere.
+                if cells.len() < 2 {
+                    continue;
+                }
+
+                let first = cells[0].into_coordinate();
+                let same_row = cells.iter().all(|i| i.into_coordinate().y == first.y);
+                let same_col = cells.iter().all(|i| i.into_coordinate().x == first.x);
+
+                let line_type = if same_row {
+                    CellGroupType::StandardRow
+                } else if same_col {
+                    CellGroupType::StandardColumn
+                } else {
+                    continue;
+                };
+
+                // Eliminate the value from the rest of the line, outside the block.
+                for index in groups
+                    .get_peer_indexes(cells[0], line_type)
+                    .filter(|&i| !block.contains(i))
+                {
+                    applied_some |= state.forget_at_index(index, value);
+                }
+
+                if applied_some {
+                    debug!(
+                        "Applied Pointing for value {value:?} in block along {line_type:?}",
+                        value = value,
+                        line_type = line_type
+                    );
+                }
+            }
+        }
+
+        if applied_some {
+            Ok(StrategyResult::AppliedChange)
+        } else {
+            Ok(StrategyResult::NoChange)
+        }
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy is not group aware")
+    }
+}
@@ -0,0 +1,311 @@
+use crate::cell_group::{CellGroupType, CellGroups, CollectIndexes};
+use crate::game_state::{GameState, InvalidGameState};
+use crate::index::Index;
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
+use crate::Value;
+use log::debug;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+/// Identifies and realizes Simple Coloring (single-digit chaining).
+///
+/// For a fixed candidate value the strategy builds a graph whose nodes are the
+/// cells still holding that value and whose edges are *conjugate pairs* — the
+/// two cells of a group in which the value appears exactly twice. Each
+/// connected component is two-colored by alternating along the edges, tracked
+/// with the [`ParityDisjointSet`] forest below. Two standard eliminations then
+/// follow:
+///
+/// * If two cells of the **same** color share a group, that color is false and
+///   the value is removed from every same-colored cell of the component.
+/// * Any uncolored cell that sees **both** colors of a component cannot hold
+///   the value and has it removed.
+pub struct SimpleColoring {
+    enabled: bool,
+}
+
+impl SimpleColoring {
+    pub fn new_box(enabled: bool) -> Box<Self> {
+        Box::new(Self { enabled })
+    }
+}
+
+/// British-spelling alias for [`SimpleColoring`].
+///
+/// The conjugate-pair colouring this strategy performs is exactly the
+/// union-find-with-edge-parity chaining described for the `Colouring`
+/// technique, so the two names refer to one implementation.
+pub type Colouring = SimpleColoring;
+
+impl Debug for SimpleColoring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Simple coloring")
+    }
+}
+
+impl Strategy for SimpleColoring {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Fiendish
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+
+        for value in Value::range() {
+            applied_some |= self.apply_for_value(state, groups, value)?;
+        }
+
+        Ok(if applied_some {
+            StrategyResult::AppliedChange
+        } else {
+            StrategyResult::NoChange
+        })
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy is not group aware")
+    }
+}
+
+impl SimpleColoring {
+    fn apply_for_value(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+        value: Value,
+    ) -> Result<bool, InvalidGameState> {
+        let mut forest = ParityDisjointSet::new();
+
+        // Build conjugate-pair edges: a value confined to two cells of a group.
+        let mut conjugate_pairs = 0usize;
+        for group in groups.iter() {
+            let cells: Vec<Index> = group
+                .iter_indexes()
+                .filter(|&i| {
+                    let cell = state.get_at_index(i);
+                    !cell.is_solved() && cell.contains(value)
+                })
+                .collect();
+
+            if cells.len() == 2 {
+                // Conjugate pair: the two cells have opposite colors.
+                conjugate_pairs += 1;
+                if forest.union(*cells[0], *cells[1], true).is_err() {
+                    // A same-root parity conflict means a direct contradiction.
+                    return Err(InvalidGameState {});
+                }
+            }
+        }
+
+        // Invariant: only values with at least one conjugate pair form a chain.
+        if conjugate_pairs == 0 {
+            return Ok(false);
+        }
+
+        // Assign a (component, color) label to every candidate cell that is part
+        // of a conjugate chain.
+        let mut colors: HashMap<u8, (u8, bool)> = HashMap::new();
+        for index in Index::range() {
+            let cell = state.get_at_index(index);
+            if cell.is_solved() || !cell.contains(value) {
+                continue;
+            }
+            if let Some((root, parity)) = forest.find_labelled(*index) {
+                colors.insert(*index, (root, parity));
+            }
+        }
+
+        if colors.is_empty() {
+            return Ok(false);
+        }
+
+        let mut applied = false;
+
+        // Rule 1: two same-colored cells in one group mark the color as false.
+        let mut false_colors: Vec<(u8, bool)> = Vec::new();
+        for group in groups.iter() {
+            let mut seen: HashMap<(u8, bool), u8> = HashMap::new();
+            for index in group.iter_indexes() {
+                if let Some(&label) = colors.get(&*index) {
+                    if seen.insert(label, *index).is_some() && !false_colors.contains(&label) {
+                        false_colors.push(label);
+                    }
+                }
+            }
+        }
+
+        for (&index, &label) in colors.iter() {
+            if false_colors.contains(&label) {
+                applied |= state.forget_at_index(Index::new(index), value);
+            }
+        }
+
+        // Rule 2: an uncolored cell that sees both colors of a component.
+        for index in Index::range() {
+            let cell = state.get_at_index(index);
+            if cell.is_solved() || !cell.contains(value) {
+                continue;
+            }
+            if colors.contains_key(&*index) {
+                continue;
+            }
+
+            let peers = match groups.get_peers_at_index(index, CollectIndexes::ExcludeSelf) {
+                Ok(peers) => peers,
+                Err(_) => continue,
+            };
+
+            // For each component, record which colors are visible.
+            let mut seen: HashMap<u8, (bool, bool)> = HashMap::new();
+            for peer in peers.iter() {
+                if let Some(&(root, parity)) = colors.get(&*peer) {
+                    let entry = seen.entry(root).or_insert((false, false));
+                    if parity {
+                        entry.1 = true;
+                    } else {
+                        entry.0 = true;
+                    }
+                }
+            }
+
+            if seen.values().any(|&(a, b)| a && b) {
+                applied |= state.forget_at_index(index, value);
+            }
+        }
+
+        if applied {
+            debug!("Applied Simple Coloring for value {value:?}", value = value);
+        }
+
+        Ok(applied)
+    }
+}
+
+/// A disjoint-set forest augmented with a parity bit per element that records
+/// whether the element shares its root's color or the opposite one.
+///
+/// It implements path compression and union by rank; merging two elements
+/// records their relative color, so a merge that would contradict an existing
+/// relation is reported as an error in near-constant time.
+struct ParityDisjointSet {
+    parent: [u8; 81],
+    rank: [u8; 81],
+    /// Parity of each node relative to its parent (`true` = opposite color).
+    parity: [bool; 81],
+}
+
+impl ParityDisjointSet {
+    fn new() -> Self {
+        let mut parent = [0u8; 81];
+        for (i, p) in parent.iter_mut().enumerate() {
+            *p = i as u8;
+        }
+        Self {
+            parent,
+            rank: [0u8; 81],
+            parity: [false; 81],
+        }
+    }
+
+    /// Finds the root of `node`, returning it alongside the accumulated parity
+    /// relative to that root, while compressing the path.
+    fn find(&mut self, node: u8) -> (u8, bool) {
+        if self.parent[node as usize] == node {
+            return (node, false);
+        }
+        let (root, parent_parity) = self.find(self.parent[node as usize]);
+        let parity = self.parity[node as usize] ^ parent_parity;
+        self.parent[node as usize] = root;
+        self.parity[node as usize] = parity;
+        (root, parity)
+    }
+
+    /// Merges `a` and `b`, recording whether they are opposite-colored.
+    ///
+    /// ## Returns
+    /// `Err` if the two nodes already share a root with a conflicting parity.
+    fn union(&mut self, a: u8, b: u8, opposite: bool) -> Result<(), ()> {
+        let (root_a, parity_a) = self.find(a);
+        let (root_b, parity_b) = self.find(b);
+
+        if root_a == root_b {
+            // Already connected; verify the relation is consistent.
+            return if (parity_a ^ parity_b) == opposite {
+                Ok(())
+            } else {
+                Err(())
+            };
+        }
+
+        // The parity between the two roots that keeps `a` and `b` consistent.
+        let relative = parity_a ^ parity_b ^ opposite;
+
+        if self.rank[root_a as usize] < self.rank[root_b as usize] {
+            self.parent[root_a as usize] = root_b;
+            self.parity[root_a as usize] = relative;
+        } else if self.rank[root_a as usize] > self.rank[root_b as usize] {
+            self.parent[root_b as usize] = root_a;
+            self.parity[root_b as usize] = relative;
+        } else {
+            self.parent[root_b as usize] = root_a;
+            self.parity[root_b as usize] = relative;
+            self.rank[root_a as usize] += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `(root, color)` label of `node` if it belongs to a chain of
+    /// more than one element, or `None` for an isolated node.
+    fn find_labelled(&mut self, node: u8) -> Option<(u8, bool)> {
+        let (root, parity) = self.find(node);
+        if root == node && self.rank[node as usize] == 0 {
+            // Isolated singleton (no conjugate pair); not part of any chain.
+            None
+        } else {
+            Some((root, parity))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParityDisjointSet;
+
+    #[test]
+    fn records_opposite_colors() {
+        let mut forest = ParityDisjointSet::new();
+        forest.union(0, 1, true).unwrap();
+        let (root0, color0) = forest.find(0);
+        let (root1, color1) = forest.find(1);
+        assert_eq!(root0, root1);
+        assert_ne!(color0, color1);
+    }
+
+    #[test]
+    fn detects_parity_conflict() {
+        let mut forest = ParityDisjointSet::new();
+        forest.union(0, 1, true).unwrap();
+        forest.union(1, 2, true).unwrap();
+        // 0 and 2 must be the same color; claiming opposite is a contradiction.
+        assert!(forest.union(0, 2, true).is_err());
+        assert!(forest.union(0, 2, false).is_ok());
+    }
+}
@@ -0,0 +1,302 @@
+use crate::cell_group::{CellGroup, CellGroups, CellGroupType};
+use crate::game_state::{GameState, InvalidGameState};
+use crate::index::Index;
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
+use crate::value::ValueBitSet;
+use crate::Value;
+use log::debug;
+use std::fmt::{Debug, Formatter};
+
+/// Identifies and realizes Naked Subsets of a configurable size.
+///
+/// A naked subset is a set of `size` cells in a group whose combined
+/// candidates amount to exactly `size` distinct values. Those values can
+/// then be removed from every other cell in the group. With `size == 2`
+/// this is a naked twin, `size == 3` a naked triple and so on, so naked
+/// quads come for free.
+pub struct NakedSubset {
+    size: usize,
+    enabled: bool,
+}
+
+impl NakedSubset {
+    pub fn new_box(size: usize, enabled: bool) -> Box<Self> {
+        Box::new(Self { size, enabled })
+    }
+}
+
+impl Debug for NakedSubset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Naked subset (size {size})", size = self.size)
+    }
+}
+
+impl Strategy for NakedSubset {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+        for group in groups.iter() {
+            applied_some |= self.apply_to_group(state, group)?;
+        }
+
+        Ok(if applied_some {
+            StrategyResult::AppliedChange
+        } else {
+            StrategyResult::NoChange
+        })
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy iterates groups directly")
+    }
+}
+
+impl NakedSubset {
+    fn apply_to_group(
+        &self,
+        state: &GameState,
+        group: &CellGroup,
+    ) -> Result<bool, InvalidGameState> {
+        // Candidate cells: unsolved cells that fit inside a subset of this size.
+        let candidates: Vec<Index> = group
+            .iter_indexes()
+            .filter(|&i| {
+                let len = state.get_at_index(i).len();
+                len >= 2 && len <= self.size
+            })
+            .collect();
+
+        if candidates.len() < self.size {
+            return Ok(false);
+        }
+
+        let mut applied_some = false;
+        for combo in combinations(&candidates, self.size) {
+            let mut union = ValueBitSet::empty();
+            for &index in &combo {
+                union.union(&state.get_at_index(index).to_bitset());
+            }
+
+            if union.len() != self.size {
+                continue;
+            }
+
+            // Remove the subset values from every other cell in the group.
+            let mut applied = false;
+            for index in group.iter_indexes().filter(|i| !combo.contains(i)) {
+                applied |= state.forget_many_at_index(index, union);
+            }
+
+            if applied {
+                debug!(
+                    "Applied Naked Subset {values:?} of size {size}",
+                    values = union,
+                    size = self.size
+                );
+                applied_some = true;
+            }
+        }
+
+        Ok(applied_some)
+    }
+}
+
+/// Identifies and realizes Hidden Subsets of a configurable size.
+///
+/// A hidden subset is a set of `size` values that, within a group, appear in
+/// exactly `size` cells. Those cells can then be restricted to just those
+/// values. With `size == 2` this is a hidden twin, `size == 3` a hidden
+/// triple and so on.
+pub struct HiddenSubset {
+    size: usize,
+    enabled: bool,
+    /// When set, subsets that are also naked subsets (the confined cells carry
+    /// no candidates beyond the subset) are skipped, since the Naked Subset
+    /// strategy already covers them. This preserves the original Hidden Twins
+    /// behavior.
+    strictly_hidden: bool,
+}
+
+impl HiddenSubset {
+    pub fn new_box(size: usize, enabled: bool) -> Box<Self> {
+        Box::new(Self {
+            size,
+            enabled,
+            strictly_hidden: true,
+        })
+    }
+
+    /// Configures whether subsets that double as naked subsets are skipped.
+    pub fn with_strictly_hidden(mut self, strictly_hidden: bool) -> Self {
+        self.strictly_hidden = strictly_hidden;
+        self
+    }
+}
+
+impl Debug for HiddenSubset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hidden subset (size {size})", size = self.size)
+    }
+}
+
+impl Strategy for HiddenSubset {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Medium
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+        for group in groups.iter() {
+            applied_some |= self.apply_to_group(state, group)?;
+        }
+
+        Ok(if applied_some {
+            StrategyResult::AppliedChange
+        } else {
+            StrategyResult::NoChange
+        })
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy iterates groups directly")
+    }
+}
+
+impl HiddenSubset {
+    fn apply_to_group(
+        &self,
+        state: &GameState,
+        group: &CellGroup,
+    ) -> Result<bool, InvalidGameState> {
+        // The values that are still open somewhere in this group.
+        let mut present = ValueBitSet::empty();
+        for index in group.iter_indexes() {
+            let cell = state.get_at_index(index);
+            if !cell.is_solved() {
+                present.union(&cell.to_bitset());
+            }
+        }
+
+        let present: Vec<Value> = present.into_iter().collect();
+        if present.len() < self.size {
+            return Ok(false);
+        }
+
+        let mut applied_some = false;
+        for combo in combinations(&present, self.size) {
+            let mut subset = ValueBitSet::empty();
+            for &value in &combo {
+                subset.insert(value);
+            }
+
+            // Cells (unsolved) that carry any of the subset values.
+            let cells: Vec<Index> = group
+                .iter_indexes()
+                .filter(|&i| {
+                    let cell = state.get_at_index(i);
+                    !cell.is_solved() && cell.contains_some(subset)
+                })
+                .collect();
+
+            // A hidden subset is confined to exactly `size` cells.
+            if cells.len() != self.size {
+                continue;
+            }
+
+            // Skip pairs/triples that are also naked subsets (the cells carry
+            // nothing beyond the subset values); those are handled by the
+            // Naked Subset strategy.
+            if self.strictly_hidden {
+                let mut union = ValueBitSet::empty();
+                for &index in &cells {
+                    union.union(&state.get_at_index(index).to_bitset());
+                }
+                if union.len() == self.size {
+                    continue;
+                }
+            }
+
+            // Restrict those cells to the subset values.
+            let mut applied = false;
+            for index in cells {
+                applied |= state.set_many_at_index(index, subset);
+            }
+
+            if applied {
+                debug!(
+                    "Applied Hidden Subset {values:?} of size {size}",
+                    values = subset,
+                    size = self.size
+                );
+                applied_some = true;
+            }
+        }
+
+        Ok(applied_some)
+    }
+}
+
+/// Produces all `size`-element combinations of the given slice, preserving
+/// order. Intended for small inputs (group cells or candidate values).
+fn combinations<T: Copy>(items: &[T], size: usize) -> Vec<Vec<T>> {
+    let mut result = Vec::new();
+    let mut current = Vec::with_capacity(size);
+    fn recurse<T: Copy>(
+        items: &[T],
+        start: usize,
+        size: usize,
+        current: &mut Vec<T>,
+        result: &mut Vec<Vec<T>>,
+    ) {
+        if current.len() == size {
+            result.push(current.clone());
+            return;
+        }
+        for i in start..items.len() {
+            current.push(items[i]);
+            recurse(items, i + 1, size, current, result);
+            current.pop();
+        }
+    }
+    if size > 0 && size <= items.len() {
+        recurse(items, 0, size, &mut current, &mut result);
+    }
+    result
+}
@@ -0,0 +1,185 @@
+use crate::cell_group::{CellGroupType, CellGroups, CollectIndexes};
+use crate::game_state::{GameState, InvalidGameState};
+use crate::index::Index;
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
+use crate::value::{Value, ValueBitSet};
+use log::debug;
+use std::fmt::{Debug, Formatter};
+
+/// Identifies and realizes the WXYZ-Wing strategy.
+///
+/// A WXYZ-Wing is a set of four cells whose candidates together span exactly
+/// four values `W, X, Y, Z`, of which exactly one — `Z` — is *non-restricted*,
+/// i.e. it appears in two wing cells that do not see each other. Because the
+/// four cells must hold the four values, `Z` is confined to the wing cells that
+/// carry it, so any cell outside the wing that sees all of them cannot hold
+/// `Z`.
+pub struct WxyzWing {
+    enabled: bool,
+}
+
+impl WxyzWing {
+    pub fn new_box(enabled: bool) -> Box<Self> {
+        Box::new(Self { enabled })
+    }
+}
+
+impl Debug for WxyzWing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WXYZ-Wing")
+    }
+}
+
+impl Strategy for WxyzWing {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Fiendish
+    }
+
+    fn always_continue(&self) -> bool {
+        false
+    }
+
+    fn apply(
+        &self,
+        state: &GameState,
+        groups: &CellGroups,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        let mut applied_some = false;
+
+        // The pivot carries at most four candidates; the remaining three wing
+        // cells are drawn from its peers.
+        for pivot in Index::range() {
+            let pivot_cell = state.get_at_index(pivot);
+            if pivot_cell.is_solved() || pivot_cell.len() > 4 {
+                continue;
+            }
+
+            let peers = match groups.get_peers_at_index(pivot, CollectIndexes::ExcludeSelf) {
+                Ok(peers) => peers,
+                Err(_) => continue,
+            };
+
+            let wing_candidates: Vec<Index> = peers
+                .iter()
+                .filter(|&i| {
+                    let len = state.get_at_index(i).len();
+                    len >= 2 && len <= 4
+                })
+                .collect();
+
+            for combo in combinations3(&wing_candidates) {
+                let cells = [pivot, combo.0, combo.1, combo.2];
+
+                let mut union = ValueBitSet::empty();
+                for &index in &cells {
+                    union.union(&state.get_at_index(index).to_bitset());
+                }
+                if union.len() != 4 {
+                    continue;
+                }
+
+                // Determine the single non-restricted candidate.
+                let mut non_restricted: Option<Value> = None;
+                let mut valid = true;
+                for value in union {
+                    let holders: Vec<Index> = cells
+                        .iter()
+                        .copied()
+                        .filter(|&i| state.get_at_index(i).contains(value))
+                        .collect();
+
+                    let restricted = holders
+                        .iter()
+                        .enumerate()
+                        .all(|(k, &a)| holders[k + 1..].iter().all(|&b| sees(groups, a, b)));
+
+                    if !restricted {
+                        if non_restricted.is_some() {
+                            // More than one non-restricted value; not a WXYZ-Wing.
+                            valid = false;
+                            break;
+                        }
+                        non_restricted = Some(value);
+                    }
+                }
+
+                let z = match (valid, non_restricted) {
+                    (true, Some(z)) => z,
+                    _ => continue,
+                };
+
+                // The wing cells that carry Z.
+                let z_cells: Vec<Index> = cells
+                    .iter()
+                    .copied()
+                    .filter(|&i| state.get_at_index(i).contains(z))
+                    .collect();
+
+                // Eliminate Z from any outside cell that sees all Z-cells.
+                for target in Index::range() {
+                    if cells.contains(&target) {
+                        continue;
+                    }
+                    let cell = state.get_at_index(target);
+                    if cell.is_solved() || !cell.contains(z) {
+                        continue;
+                    }
+                    if z_cells.iter().all(|&zc| sees(groups, target, zc)) {
+                        if state.forget_at_index(target, z) {
+                            applied_some = true;
+                            debug!(
+                                "Applied WXYZ-Wing removing {z:?} at {target:?}",
+                                z = z,
+                                target = target
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if applied_some {
+            Ok(StrategyResult::AppliedChange)
+        } else {
+            Ok(StrategyResult::NoChange)
+        }
+    }
+
+    fn apply_in_group(
+        &self,
+        _state: &GameState,
+        _groups: &CellGroups,
+        _group_type: CellGroupType,
+    ) -> Result<StrategyResult, InvalidGameState> {
+        unimplemented!("This strategy is not group aware")
+    }
+}
+
+/// Determines whether two cells share any group, i.e. whether they see each
+/// other.
+fn sees(groups: &CellGroups, a: Index, b: Index) -> bool {
+    if a == b {
+        return false;
+    }
+    match groups.get_peers_at_index(a, CollectIndexes::ExcludeSelf) {
+        Ok(peers) => peers.contains(b),
+        Err(_) => false,
+    }
+}
+
+/// Produces all 3-element combinations of the slice.
+fn combinations3(items: &[Index]) -> Vec<(Index, Index, Index)> {
+    let mut result = Vec::new();
+    for i in 0..items.len() {
+        for j in (i + 1)..items.len() {
+            for k in (j + 1)..items.len() {
+                result.push((items[i], items[j], items[k]));
+            }
+        }
+    }
+    result
+}
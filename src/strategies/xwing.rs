@@ -1,12 +1,21 @@
 use crate::cell_group::{CellGroupType, CellGroups};
 use crate::game_state::{GameState, InvalidGameState};
-use crate::index::{CollectIndexBitSet, Index};
-use crate::strategies::{Strategy, StrategyResult};
+use crate::strategies::{Difficulty, Strategy, StrategyResult};
 use crate::{Coordinate, Value};
-use log::{debug, trace};
+use log::debug;
 use std::fmt::{Debug, Formatter};
 
 /// Identifies and realizes the X-Wing strategy.
+///
+/// The X-Wing is the order-2 [fish](crate::strategies::Fish): for a candidate
+/// value, two base rows whose candidates occupy the *same* two columns form a
+/// rectangle. Each base row must place the value in one of those columns, so
+/// the value can be removed from every other cell of those two columns. The
+/// same reasoning applies with rows and columns swapped.
+///
+/// It is kept as a standalone strategy (rather than only the generalized fish)
+/// so it can be toggled independently and named explicitly in a
+/// [`SolveReport`](crate::default_solver::SolveReport).
 pub struct XWing {
     enabled: bool,
 }
@@ -28,6 +37,10 @@ impl Strategy for XWing {
         self.enabled
     }
 
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Fiendish
+    }
+
     fn always_continue(&self) -> bool {
         false
     }
@@ -35,126 +48,20 @@ impl Strategy for XWing {
     fn apply(
         &self,
         state: &GameState,
-        groups: &CellGroups,
+        _groups: &CellGroups,
     ) -> Result<StrategyResult, InvalidGameState> {
-        let mut xwings: Vec<XWingCoords> = Vec::default();
-
-        for value in Value::range() {
-            // Identify all the cells that are not solved and contain the value under test.
-            let indexes = state
-                .iter_indexed()
-                .filter(|&cell| !cell.is_solved() && cell.contains(value))
-                .map(|cell| cell.index)
-                .collect_bitset();
-
-            // For the X-Wing to work, we need at least four matching cells
-            // in order to form a single rectangle.
-            if indexes.len() < 4 {
-                continue;
-            }
-
-            // For each matching cell, scan for rectangles.
-            for tl in indexes {
-                let tl = tl.into_coordinate();
-
-                for x in (tl.x + 1)..9 {
-                    let tr = Coordinate::new(x, tl.y);
-                    let has_tr = indexes.contains_coord(tr);
-                    if !has_tr {
-                        continue;
-                    }
-
-                    for y in (tl.y + 1)..9 {
-                        let bl = Coordinate::new(tl.x, y);
-                        let br = Coordinate::new(x, y);
-
-                        let has_bl = indexes.contains_coord(bl);
-                        let has_br = indexes.contains_coord(br);
-
-                        // Ensure we found a rectangle.
-                        if !(has_bl && has_br) {
-                            continue;
-                        }
-
-                        // Ensure that only two matches exist in both rows OR both columns.
-                        let mut top_count = 0;
-                        let mut bottom_count = 0;
-                        let mut left_count = 0;
-                        let mut right_count = 0;
-                        for xy in 0..9 {
-                            top_count += indexes.contains_xy(xy, tr.y) as u32;
-                            bottom_count += indexes.contains_xy(xy, br.y) as u32;
-                            left_count += indexes.contains_xy(tl.x, xy) as u32;
-                            right_count += indexes.contains_xy(br.x, xy) as u32;
-                        }
-
-                        if !(left_count == 2 && right_count == 2)
-                            && !(top_count == 2 && bottom_count == 2)
-                        {
-                            continue;
-                        }
-
-                        trace!(
-                            "Identified X-Wing for value {value:?} at {tl:?}, {tr:?}, {bl:?}, {br:?}",
-                            value = value,
-                            tl = tl,
-                            tr = tr,
-                            bl = bl,
-                            br = br
-                        );
-                        xwings.push(XWingCoords {
-                            value,
-                            top_left: tl.into_index(),
-                            top_right: tr.into_index(),
-                            bottom_left: bl.into_index(),
-                            bottom_right: br.into_index(),
-                        })
-                    }
-                }
-            }
-        }
-
-        if xwings.is_empty() {
-            return Ok(StrategyResult::NoChange);
-        }
-
         let mut applied_some = false;
-        for xwing in xwings {
-            debug_assert!(xwing.top_left != xwing.top_right);
-            debug_assert!(xwing.top_left != xwing.bottom_left);
-            debug_assert!(xwing.top_right != xwing.bottom_right);
-            debug_assert!(xwing.top_right != xwing.bottom_left);
-
-            let mut applied_xwing = false;
-            for index in groups
-                .get_peer_indexes(xwing.top_left, CellGroupType::StandardRow)
-                .chain(groups.get_peer_indexes(xwing.bottom_left, CellGroupType::StandardRow))
-                .chain(groups.get_peer_indexes(xwing.top_left, CellGroupType::StandardColumn))
-                .chain(groups.get_peer_indexes(xwing.top_right, CellGroupType::StandardColumn))
-                .filter(|idx| !xwing.eq(idx))
-            {
-                applied_xwing |= state.forget_at_index(index, xwing.value);
-            }
 
-            applied_some |= applied_xwing;
-            if applied_xwing {
-                debug!(
-                    "Applied X-Wing for value {value:?} at {tl:?}, {tr:?}, {bl:?}, {br:?}",
-                    value = xwing.value,
-                    tl = xwing.top_left,
-                    tr = xwing.top_right,
-                    bl = xwing.bottom_left,
-                    br = xwing.bottom_right
-                );
-            }
+        for value in Value::range() {
+            applied_some |= self.search(state, value, Orientation::Row);
+            applied_some |= self.search(state, value, Orientation::Column);
         }
 
-        if applied_some {
-            Ok(StrategyResult::AppliedChange)
+        Ok(if applied_some {
+            StrategyResult::AppliedChange
         } else {
-            trace!("No X-Wings could be applied");
-            Ok(StrategyResult::NoChange)
-        }
+            StrategyResult::NoChange
+        })
     }
 
     fn apply_in_group(
@@ -167,20 +74,69 @@ impl Strategy for XWing {
     }
 }
 
-struct XWingCoords {
-    value: Value,
-    top_left: Index,
-    top_right: Index,
-    bottom_left: Index,
-    bottom_right: Index,
+#[derive(Copy, Clone)]
+enum Orientation {
+    Row,
+    Column,
 }
 
-impl PartialEq<Index> for XWingCoords {
-    #[inline]
-    fn eq(&self, other: &Index) -> bool {
-        self.top_left == *other
-            || self.top_right == *other
-            || self.bottom_left == *other
-            || self.bottom_right == *other
+impl XWing {
+    fn search(&self, state: &GameState, value: Value, orientation: Orientation) -> bool {
+        // For each base line collect the cover positions the value can occupy,
+        // packed into a 9-bit mask so two base lines can be compared directly.
+        let mut masks = [0u16; 9];
+        for base in 0..9u8 {
+            let mut mask = 0u16;
+            for cover in 0..9u8 {
+                let (x, y) = match orientation {
+                    Orientation::Row => (cover, base),
+                    Orientation::Column => (base, cover),
+                };
+                let cell = state.get_at_coord(Coordinate::new(x, y));
+                if !cell.is_solved() && cell.contains(value) {
+                    mask |= 1 << cover;
+                }
+            }
+            masks[base as usize] = mask;
+        }
+
+        let mut applied_some = false;
+        for a in 0..9usize {
+            // A base line qualifies only if it holds the value in exactly two
+            // covers.
+            if masks[a].count_ones() != 2 {
+                continue;
+            }
+            for b in (a + 1)..9usize {
+                if masks[b] != masks[a] {
+                    continue;
+                }
+
+                // `a` and `b` are the base lines; the two set bits are the
+                // cover lines that define the X-Wing rectangle.
+                for cover in 0..9u8 {
+                    if masks[a] & (1 << cover) == 0 {
+                        continue;
+                    }
+                    for base in 0..9u8 {
+                        if base as usize == a || base as usize == b {
+                            continue;
+                        }
+                        let (x, y) = match orientation {
+                            Orientation::Row => (cover, base),
+                            Orientation::Column => (base, cover),
+                        };
+                        let index = Coordinate::new(x, y).into_index();
+                        applied_some |= state.forget_at_index(index, value);
+                    }
+                }
+
+                if applied_some {
+                    debug!("Applied X-Wing for value {value:?}", value = value);
+                }
+            }
+        }
+
+        applied_some
     }
 }
@@ -31,6 +31,12 @@ impl Value {
         Ok(Self(value))
     }
 
+    /// Iterates over every legal Sudoku value, `1..=9`.
+    #[inline]
+    pub fn range() -> impl Iterator<Item = Value> {
+        (1..=9).map(|v| unsafe { Value::new_unchecked(v) })
+    }
+
     /// Uses [`NonZeroU8::new_unchecked`] to construct the value.
     #[inline]
     const unsafe fn new_unchecked(value: u8) -> Self {
@@ -75,72 +81,67 @@ impl TryFrom<u8> for Value {
 #[error("The specified value `{0}` is out of range")]
 pub struct ValueOutOfRangeError(u8);
 
-/// A simple bitset for storing regular Sudoku-sized (i.e., up to 9) cell values.
+/// The default number of distinct symbols on a board: the classic 9×9 game.
+pub const DEFAULT_SYMBOL_COUNT: usize = 9;
+
+/// A bitset of the cell values on a classic 9×9 board.
 ///
 /// ## Technical Notes
-/// Practically this implementation allows for storing up to 65535 different indexes.
+/// The values are packed into a single 64-bit block, addressed by value minus
+/// one. Larger boards would back the set with a `[u64; (N + 63) / 64]` block
+/// array the way `fixedbitset` does, which is the natural extension once the
+/// board size (and [`Value`]'s range) becomes configurable.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ValueBitSet {
-    /// We anticipate at most 9 distinct values on a standard Sudoku game.
-    /// We use a 16-bit type here to directly encode the field values,
-    /// even though this wastes 7 bits.
-    state: u16,
+    /// The value bits, with value `v` stored at bit `v - 1`.
+    state: u64,
 }
 
 impl ValueBitSet {
-    /// The mask for storing the actual values.
-    const MASK: u16 = 0b111111111u16;
+    /// The mask selecting the low bits that actually encode values.
+    const MASK: u64 = (1u64 << DEFAULT_SYMBOL_COUNT) - 1;
 
     pub const fn empty() -> Self {
         Self { state: 0 }
     }
 
     pub const fn all_values() -> Self {
-        Self::empty()
-            .with_value(Value::ONE)
-            .with_value(Value::TWO)
-            .with_value(Value::THREE)
-            .with_value(Value::FOUR)
-            .with_value(Value::FIVE)
-            .with_value(Value::SIX)
-            .with_value(Value::SEVEN)
-            .with_value(Value::EIGHT)
-            .with_value(Value::NINE)
+        Self { state: Self::MASK }
     }
 
     #[inline]
     pub const fn with_value(mut self, value: Value) -> Self {
         debug_assert!(value.get() <= 9);
-        let value = value.get() as u16;
+        let value = value.get() as u64;
         // Since the value is a non-zero u8 we subtract one for the first bit.
-        self.state |= (1u16 << (value - 1)) & Self::MASK;
+        self.state |= (1u64 << (value - 1)) & Self::MASK;
         self
     }
 
     #[inline]
     pub fn insert(&mut self, value: Value) -> &mut Self {
         debug_assert!(value.get() <= 9);
-        let value = value.get() as u16;
+        let value = value.get() as u64;
         // Since the value is a non-zero u8 we subtract one for the first bit.
-        self.state |= (1u16 << (value - 1)) & Self::MASK;
+        self.state |= (1u64 << (value - 1)) & Self::MASK;
         self
     }
 
     #[inline]
     pub const fn without_value(mut self, value: Value) -> Self {
         debug_assert!(value.get() <= 9);
-        let value = value.get() as u128;
+        let value = value.get() as u64;
         // Since the value is a non-zero u8 we subtract one for the first bit.
-        self.state &= (!(1u16 << (value - 1))) & Self::MASK;
+        self.state &= (!(1u64 << (value - 1))) & Self::MASK;
         self
     }
 
     #[inline]
     pub fn remove(&mut self, value: Value) -> &mut Self {
         debug_assert!(value.get() <= 9);
-        let value = value.get() as u16;
+        let value = value.get() as u64;
         // Since the value is a non-zero u8 we subtract one for the first bit.
-        self.state &= (!(1u16 << (value - 1))) & Self::MASK;
+        self.state &= (!(1u64 << (value - 1))) & Self::MASK;
         self
     }
 
@@ -154,9 +155,9 @@ impl ValueBitSet {
     #[inline]
     pub fn set_to(&mut self, value: Value) -> &mut Self {
         debug_assert!(value.get() <= 9);
-        let value = value.get() as u16;
+        let value = value.get() as u64;
         // Since the value is a non-zero u8 we subtract one for the first bit.
-        self.state = (1u16 << (value - 1)) & Self::MASK;
+        self.state = (1u64 << (value - 1)) & Self::MASK;
         self
     }
 
@@ -172,21 +173,33 @@ impl ValueBitSet {
         self
     }
 
+    #[inline]
+    pub const fn with_intersection(mut self, other: ValueBitSet) -> Self {
+        self.state &= other.state & Self::MASK;
+        self
+    }
+
+    #[inline]
+    pub fn intersect(&mut self, other: &ValueBitSet) -> &mut Self {
+        self.state &= other.state & Self::MASK;
+        self
+    }
+
     #[inline]
     pub const fn contains(&self, value: Value) -> bool {
         debug_assert!(value.get() <= 9);
-        let value = value.get() as u16;
+        let value = value.get() as u64;
         // Since the value is a non-zero u8 we subtract one for the first bit.
-        let flag = self.state & (1u16 << (value - 1));
+        let flag = self.state & (1u64 << (value - 1));
         flag != 0
     }
 
     #[inline]
     pub const fn is_exactly(&self, value: Value) -> bool {
         debug_assert!(value.get() <= 9);
-        let value = value.get() as u16;
+        let value = value.get() as u64;
         // Since the value is a non-zero u8 we subtract one for the first bit.
-        let flag = self.state & (1u16 << (value - 1));
+        let flag = self.state & (1u64 << (value - 1));
         flag == self.state
     }
 
@@ -214,11 +227,39 @@ impl ValueBitSet {
     #[inline]
     pub const fn iter(&self) -> ValueBitSetIter {
         ValueBitSetIter {
-            value: *self,
-            index: 0,
+            state: self.state & Self::MASK,
         }
     }
 
+    /// The lowest value in the set, or `None` if the set is empty.
+    #[inline]
+    pub const fn first(&self) -> Option<Value> {
+        let state = self.state & Self::MASK;
+        if state == 0 {
+            None
+        } else {
+            Some(unsafe { Value::new_unchecked(state.trailing_zeros() as u8 + 1) })
+        }
+    }
+
+    /// The highest value in the set, or `None` if the set is empty.
+    #[inline]
+    pub const fn last(&self) -> Option<Value> {
+        let state = self.state & Self::MASK;
+        if state == 0 {
+            None
+        } else {
+            Some(unsafe { Value::new_unchecked((63 - state.leading_zeros()) as u8 + 1) })
+        }
+    }
+
+    /// The `n`-th value in ascending order (zero-based), or `None` if the set
+    /// holds fewer than `n + 1` values.
+    #[inline]
+    pub fn nth_value(&self, n: usize) -> Option<Value> {
+        self.iter().nth(n)
+    }
+
     /// Reduces this set to a single value.
     ///
     /// ## Returns
@@ -229,10 +270,10 @@ impl ValueBitSet {
             return None;
         }
 
-        let pow2 = self.state.trailing_zeros() as u16;
+        let pow2 = self.state.trailing_zeros() as u64;
 
         // Ensure that exactly one bit is set.
-        let test = (1u16 << pow2) & Self::MASK;
+        let test = (1u64 << pow2) & Self::MASK;
         if self.state != test {
             return None;
         }
@@ -240,12 +281,142 @@ impl ValueBitSet {
         // Zero is disallowed, so we add one.
         Some(unsafe { Value::new_unchecked(pow2 as u8 + 1) })
     }
+
+    /// The set of values present in both this set and `other`.
+    #[inline]
+    pub const fn intersection(&self, other: &ValueBitSet) -> Self {
+        Self {
+            state: self.state & other.state & Self::MASK,
+        }
+    }
+
+    /// The set of values present in this set but not in `other`.
+    #[inline]
+    pub const fn difference(&self, other: &ValueBitSet) -> Self {
+        Self {
+            state: self.state & !other.state & Self::MASK,
+        }
+    }
+
+    /// Removes every value contained in `other` from this set in place.
+    #[inline]
+    pub fn without_many(&mut self, other: &ValueBitSet) -> &mut Self {
+        self.state &= !other.state & Self::MASK;
+        self
+    }
+
+    /// The set of values present in exactly one of the two sets.
+    #[inline]
+    pub const fn symmetric_difference(&self, other: &ValueBitSet) -> Self {
+        Self {
+            state: (self.state ^ other.state) & Self::MASK,
+        }
+    }
+
+    /// The set of legal values not present in this set.
+    #[inline]
+    pub const fn complement(&self) -> Self {
+        Self {
+            state: !self.state & Self::MASK,
+        }
+    }
+
+    /// Whether every value of this set is also contained in `other`.
+    #[inline]
+    pub const fn is_subset(&self, other: &ValueBitSet) -> bool {
+        (self.state & other.state) == (self.state & Self::MASK)
+    }
+
+    /// Whether this set contains every value of `other`.
+    #[inline]
+    pub const fn is_superset(&self, other: &ValueBitSet) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Whether the two sets share no values.
+    #[inline]
+    pub const fn is_disjoint(&self, other: &ValueBitSet) -> bool {
+        (self.state & other.state & Self::MASK) == 0
+    }
+}
+
+impl std::ops::BitAnd for ValueBitSet {
+    type Output = ValueBitSet;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(&rhs)
+    }
+}
+
+impl std::ops::BitAndAssign for ValueBitSet {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.state &= rhs.state & Self::MASK;
+    }
+}
+
+impl std::ops::BitOr for ValueBitSet {
+    type Output = ValueBitSet;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.with_union(&rhs)
+    }
+}
+
+impl std::ops::BitOrAssign for ValueBitSet {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.state |= rhs.state & Self::MASK;
+    }
+}
+
+impl std::ops::BitXor for ValueBitSet {
+    type Output = ValueBitSet;
+
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(&rhs)
+    }
+}
+
+impl std::ops::BitXorAssign for ValueBitSet {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.state = (self.state ^ rhs.state) & Self::MASK;
+    }
+}
+
+impl std::ops::Not for ValueBitSet {
+    type Output = ValueBitSet;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl std::ops::Sub for ValueBitSet {
+    type Output = ValueBitSet;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(&rhs)
+    }
+}
+
+impl std::ops::SubAssign for ValueBitSet {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.state &= !rhs.state & Self::MASK;
+    }
 }
 
 impl From<&[u8]> for ValueBitSet {
     #[inline]
     fn from(values: &[u8]) -> Self {
-        let mut state = 0u16;
+        let mut state = 0u64;
         for value in values {
             debug_assert_ne!(*value, 0);
             state |= 1 << (value - 1);
@@ -254,9 +425,14 @@ impl From<&[u8]> for ValueBitSet {
     }
 }
 
+/// A branchless iterator over the values of a [`ValueBitSet`].
+///
+/// Each step jumps straight to the lowest (or, for [`DoubleEndedIterator`], the
+/// highest) set bit via `trailing_zeros`/`leading_zeros` and clears it, rather
+/// than testing every candidate bit in turn.
 pub struct ValueBitSetIter {
-    value: ValueBitSet,
-    index: u8,
+    /// The remaining, already-masked value bits.
+    state: u64,
 }
 
 impl IntoIterator for ValueBitSet {
@@ -272,30 +448,46 @@ impl IntoIterator for ValueBitSet {
 impl Iterator for ValueBitSetIter {
     type Item = Value;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let state = self.value.state;
-        let mut index = self.index;
-        while index < 9 {
-            let test = (state >> index) & 0b1;
-            index += 1;
-            if test != 0 {
-                self.index = index;
-                return Some(unsafe { Value::new_unchecked(index) });
-            }
+        if self.state == 0 {
+            return None;
         }
+        let bit = self.state.trailing_zeros();
+        // Clear the lowest set bit.
+        self.state &= self.state - 1;
+        Some(unsafe { Value::new_unchecked(bit as u8 + 1) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.state.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
 
-        self.index = 10;
-        None
+impl DoubleEndedIterator for ValueBitSetIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.state == 0 {
+            return None;
+        }
+        let bit = 63 - self.state.leading_zeros();
+        // Clear the highest set bit.
+        self.state &= !(1u64 << bit);
+        Some(unsafe { Value::new_unchecked(bit as u8 + 1) })
     }
 }
 
+impl ExactSizeIterator for ValueBitSetIter {}
+
 impl From<&[Value]> for ValueBitSet {
     #[inline]
     fn from(values: &[Value]) -> Self {
-        let mut state = 0u16;
+        let mut state = 0u64;
         for value in values {
             // Since the value is a non-zero u8 we subtract one for the first bit.
-            state |= 1u16 << (value.get() - 1);
+            state |= 1u64 << (value.get() - 1);
         }
         Self { state }
     }
@@ -304,11 +496,11 @@ impl From<&[Value]> for ValueBitSet {
 impl From<&[ValueOption]> for ValueBitSet {
     #[inline]
     fn from(values: &[ValueOption]) -> Self {
-        let mut state = 0u16;
+        let mut state = 0u64;
         for value in values {
             if let Some(value) = value {
                 // Since the value is a non-zero u8 we subtract one for the first bit.
-                state |= 1u16 << (value.get() - 1);
+                state |= 1u64 << (value.get() - 1);
             }
         }
         Self { state }
@@ -331,6 +523,12 @@ pub trait IntoValueOptions {
     fn into(self) -> [ValueOption; 81];
 }
 
+impl IntoValueOptions for [ValueOption; 81] {
+    fn into(self) -> [ValueOption; 81] {
+        self
+    }
+}
+
 impl IntoValueOptions for [u8; 81] {
     fn into(self) -> [ValueOption; 81] {
         let mut values = [None; 81];
@@ -452,6 +650,27 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn double_ended_iteration() {
+        let set = ValueBitSet::from([Value::TWO, Value::FIVE, Value::NINE].as_slice());
+
+        let mut iter = set.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(Value::TWO));
+        assert_eq!(iter.next_back(), Some(Value::NINE));
+        assert_eq!(iter.next(), Some(Value::FIVE));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(set.first(), Some(Value::TWO));
+        assert_eq!(set.last(), Some(Value::NINE));
+        assert_eq!(set.nth_value(1), Some(Value::FIVE));
+        assert_eq!(set.nth_value(3), None);
+
+        assert_eq!(ValueBitSet::empty().first(), None);
+        assert_eq!(ValueBitSet::empty().last(), None);
+    }
+
     #[test]
     pub fn all_values() {
         let set = ValueBitSet::all_values();
@@ -542,4 +761,54 @@ mod tests {
 
         assert_eq!(bitset.as_single_value(), Some(c));
     }
+
+    #[test]
+    fn set_algebra() {
+        let a = ValueBitSet::from([Value::ONE, Value::TWO, Value::THREE].as_slice());
+        let b = ValueBitSet::from([Value::THREE, Value::FOUR].as_slice());
+
+        assert_eq!(a.intersection(&b), ValueBitSet::from([Value::THREE].as_slice()));
+        assert_eq!(
+            a.difference(&b),
+            ValueBitSet::from([Value::ONE, Value::TWO].as_slice())
+        );
+        assert_eq!(
+            a.symmetric_difference(&b),
+            ValueBitSet::from([Value::ONE, Value::TWO, Value::FOUR].as_slice())
+        );
+        assert_eq!(a.complement().len(), 6);
+        assert!(!a.complement().contains(Value::ONE));
+
+        assert!(ValueBitSet::from([Value::ONE].as_slice()).is_subset(&a));
+        assert!(a.is_superset(&ValueBitSet::from([Value::ONE].as_slice())));
+        assert!(a.is_disjoint(&ValueBitSet::from([Value::NINE].as_slice())));
+        assert!(!a.is_disjoint(&b));
+    }
+
+    #[test]
+    fn operator_traits() {
+        let a = ValueBitSet::from([Value::ONE, Value::TWO, Value::THREE].as_slice());
+        let b = ValueBitSet::from([Value::THREE, Value::FOUR].as_slice());
+
+        assert_eq!(a & b, a.intersection(&b));
+        assert_eq!(a | b, a.with_union(&b));
+        assert_eq!(a ^ b, a.symmetric_difference(&b));
+        assert_eq!(a - b, a.difference(&b));
+        assert_eq!(!a, a.complement());
+        // The 55 unused high bits must never leak through the complement.
+        assert_eq!((!a).len(), 6);
+
+        let mut c = a;
+        c &= b;
+        assert_eq!(c, a & b);
+        let mut d = a;
+        d |= b;
+        assert_eq!(d, a | b);
+        let mut e = a;
+        e ^= b;
+        assert_eq!(e, a ^ b);
+        let mut f = a;
+        f -= b;
+        assert_eq!(f, a - b);
+    }
 }